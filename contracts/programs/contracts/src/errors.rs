@@ -30,7 +30,131 @@ pub enum RenewraError {
     #[msg("Signer is not authorized (admin key required)")]
     InvalidAuthority = 6006,
 
-    /// Redemption queue has reached max capacity
-    #[msg("Redemption queue has reached max capacity")]
-    RedemptionQueueFull = 6007,
+    /// Redemption request is not in the required status for this action
+    #[msg("Redemption request is not in the required status for this action")]
+    InvalidRedemptionStatus = 6009,
+
+    /// Treasury does not hold enough USDC for this payout
+    #[msg("Treasury does not hold enough USDC for this payout")]
+    InsufficientTreasuryFunds = 6010,
+
+    /// Guardian can pause the fund but only the admin can unpause it
+    #[msg("Guardian can pause the fund but only the admin can unpause it")]
+    GuardianCannotUnpause = 6011,
+
+    /// Fee exceeds the maximum allowed basis points
+    #[msg("Fee exceeds the maximum allowed basis points")]
+    FeeTooHigh = 6012,
+
+    /// Next oracle signer must be a new, non-default key
+    #[msg("Next oracle signer must be a new, non-default key")]
+    InvalidOracleRotation = 6013,
+
+    /// NAV update exceeds the maximum allowed deviation from the current NAV
+    #[msg("NAV update exceeds the maximum allowed deviation from the current NAV")]
+    NavDeviationTooLarge = 6014,
+
+    /// Governance parameter is out of its allowed range
+    #[msg("Governance parameter is out of its allowed range")]
+    InvalidGovernanceParams = 6015,
+
+    /// NAV is older than the maximum allowed age
+    #[msg("NAV is older than the maximum allowed age")]
+    StaleNav = 6016,
+
+    /// Oracle has already submitted a NAV in the pending round
+    #[msg("Oracle has already submitted a NAV in the pending round")]
+    DuplicateNavSubmission = 6017,
+
+    /// Oracle set configuration is invalid
+    #[msg("Oracle set configuration is invalid")]
+    InvalidOracleConfig = 6018,
+
+    /// NAV oracle already uses the fixed-point representation
+    #[msg("NAV oracle already uses the fixed-point representation")]
+    NavAlreadyMigrated = 6019,
+
+    /// Treasury account does not match the fund treasury PDA
+    #[msg("Treasury account does not match the fund treasury PDA")]
+    InvalidTreasury = 6020,
+
+    /// REI mint or token account does not belong to the fund's REI mint
+    #[msg("Account does not match the fund's REI mint")]
+    InvalidReitMint = 6021,
+
+    /// USDC mint or token account does not belong to the accepted USDC mint
+    #[msg("Account does not match the accepted USDC mint")]
+    InvalidUsdcMint = 6022,
+
+    /// Fee recipient token account is not owned by governance.fee_recipient
+    #[msg("Fee recipient account does not match governance")]
+    InvalidFeeRecipient = 6023,
+
+    /// Fee bucket destination does not match the account configured in governance
+    #[msg("Fee destination account does not match governance")]
+    InvalidFeeDestination = 6024,
+
+    /// Subscription is below governance.min_subscription
+    #[msg("Subscription is below the minimum size")]
+    BelowMinSubscription = 6025,
+
+    /// Subscription exceeds governance.max_subscription_per_tx
+    #[msg("Subscription exceeds the per-transaction maximum")]
+    SubscriptionTooLarge = 6026,
+
+    /// Investor's lifetime deposits would exceed governance.max_investor_deposits
+    #[msg("Investor deposit cap exceeded")]
+    InvestorCapExceeded = 6027,
+
+    /// Fund-wide deposits would exceed governance.max_total_deposits
+    #[msg("Fund deposit cap exceeded")]
+    FundCapExceeded = 6028,
+
+    /// Output amount fell below the caller's minimum
+    #[msg("Slippage exceeded: output below the requested minimum")]
+    SlippageExceeded = 6029,
+
+    /// Fund requires KYC and no investor attestation was supplied
+    #[msg("A valid investor attestation is required")]
+    KycRequired = 6030,
+
+    /// Investor attestation has passed its expiry
+    #[msg("Investor attestation has expired")]
+    AttestationExpired = 6031,
+
+    /// Investor attestation was revoked by the compliance authority
+    #[msg("Investor attestation has been revoked")]
+    AttestationRevoked = 6032,
+
+    /// Attestation expiry or jurisdiction code is invalid
+    #[msg("Invalid attestation parameters")]
+    InvalidAttestation = 6033,
+
+    /// Token metadata URI is empty or longer than MAX_URI_LEN
+    #[msg("Invalid token metadata URI")]
+    InvalidMetadataUri = 6034,
+
+    /// NAV history has no checkpoint to compute a TWAP from
+    #[msg("Not enough NAV history to compute a TWAP")]
+    InsufficientNavHistory = 6035,
+
+    /// Account is not in the expected baseline layout
+    #[msg("Account is not in the legacy layout expected by the migration")]
+    InvalidLegacyAccount = 6036,
+
+    /// REI account is not the holder's associated token account
+    #[msg("REI account must be the holder's associated token account")]
+    InvalidReitAccount = 6037,
+
+    /// Baseline redemption queue still holds unsettled requests
+    #[msg("Legacy redemption queue has open requests that would be dropped")]
+    LegacyRedemptionsPending = 6038,
+
+    /// A scheduled oracle rotation has not completed yet
+    #[msg("An oracle rotation is already in progress")]
+    OracleRotationInProgress = 6039,
+
+    /// Management fee must be settled but the mint or recipient account is missing
+    #[msg("REI mint and fee recipient account are required to settle the management fee")]
+    FeeAccountsRequired = 6040,
 }
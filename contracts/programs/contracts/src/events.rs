@@ -1,5 +1,28 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{PricingMode, YieldMode};
+
+/// Emitted when a NAV round closes, either finalized by quorum or discarded
+#[event]
+pub struct NavRoundClosedEvent {
+    pub round_id: u64,
+    pub final_nav: u64,
+    pub finalized: bool,
+    pub participants: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted when the oracle submits a NAV outside the deviation band and the fund auto-pauses
+#[event]
+pub struct NavAnomalyEvent {
+    pub current_nav: u64,
+    pub rejected_nav: u64,
+    pub deviation_bps: u64,
+    pub max_deviation_bps: u16,
+    pub timestamp: i64,
+    pub oracle_signer: Pubkey,
+}
+
 /// Emitted when a user subscribes (mints) tokens
 #[event]
 pub struct SubscribeEvent {
@@ -24,6 +47,55 @@ pub struct DistributeYieldEvent {
     pub yield_amount: u64,
     pub total_token_supply: u64,
     pub per_token_yield: u128,
+    pub reward_per_token: u128,
+    pub mode: YieldMode,
+    pub nav_after: u64,
+}
+
+/// Emitted when management fee REI is minted to the fee recipient
+#[event]
+pub struct ManagementFeeAccruedEvent {
+    pub fee_tokens: u64,
+    pub total_token_supply: u64,
+    pub management_fee_bps: u16,
+    pub fee_recipient: Pubkey,
+    pub accrued_until: i64,
+}
+
+/// Emitted when earmarked fees are swept out of the treasury to their buckets
+#[event]
+pub struct FeesSweptEvent {
+    pub operations_amount: u64,
+    pub reserve_amount: u64,
+    pub dao_amount: u64,
+    pub swept_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the compliance authority issues or renews an investor attestation
+#[event]
+pub struct AttestationIssuedEvent {
+    pub investor: Pubkey,
+    pub jurisdiction: [u8; 2],
+    pub expires_at: i64,
+    pub issued_by: Pubkey,
+}
+
+/// Emitted when the compliance authority revokes an investor attestation
+#[event]
+pub struct AttestationRevokedEvent {
+    pub investor: Pubkey,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a holder claims accrued yield
+#[event]
+pub struct YieldClaimedEvent {
+    pub holder: Pubkey,
+    pub usdc_amount: u64,
+    pub reward_per_token: u128,
+    pub timestamp: i64,
 }
 
 /// Emitted when a user requests redemption
@@ -35,6 +107,36 @@ pub struct RedeemRequestEvent {
     pub request_id: u64,
 }
 
+/// Emitted when the admin approves a queued redemption request
+#[event]
+pub struct RedeemApprovedEvent {
+    pub requester: Pubkey,
+    pub token_amount: u64,
+    pub approved_at: i64,
+    pub request_id: u64,
+}
+
+/// Emitted when an approved redemption is settled (tokens burned, USDC paid out)
+#[event]
+pub struct RedeemSettledEvent {
+    pub requester: Pubkey,
+    pub token_amount: u64,
+    pub usdc_amount: u64,
+    pub fee_amount: u64,
+    pub nav_at_settlement: u64,
+    pub settled_at: i64,
+    pub request_id: u64,
+}
+
+/// Emitted when a requester cancels a pending redemption
+#[event]
+pub struct RedeemCancelledEvent {
+    pub requester: Pubkey,
+    pub token_amount: u64,
+    pub cancelled_at: i64,
+    pub request_id: u64,
+}
+
 /// Emitted when fund is paused or unpaused
 #[event]
 pub struct PauseEvent {
@@ -42,3 +144,105 @@ pub struct PauseEvent {
     pub triggered_by: Pubkey,
     pub timestamp: i64,
 }
+
+/// Emitted when the admin updates governance parameters
+#[event]
+pub struct GovernanceUpdatedEvent {
+    pub old_management_fee_bps: u16,
+    pub new_management_fee_bps: u16,
+    pub old_mint_fee_bps: u16,
+    pub new_mint_fee_bps: u16,
+    pub old_redemption_fee_bps: u16,
+    pub new_redemption_fee_bps: u16,
+    pub old_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+    pub old_max_nav_deviation_bps: u16,
+    pub new_max_nav_deviation_bps: u16,
+    pub old_nav_deviation_auto_pause: bool,
+    pub new_nav_deviation_auto_pause: bool,
+    pub old_max_nav_age_secs: i64,
+    pub new_max_nav_age_secs: i64,
+    pub old_pricing_mode: PricingMode,
+    pub new_pricing_mode: PricingMode,
+    pub old_twap_window_secs: i64,
+    pub new_twap_window_secs: i64,
+    pub old_fee_recipient: Pubkey,
+    pub new_fee_recipient: Pubkey,
+    pub old_fee_split_bps: [u16; 3],
+    pub new_fee_split_bps: [u16; 3],
+    pub old_fee_operations_account: Pubkey,
+    pub new_fee_operations_account: Pubkey,
+    pub old_fee_reserve_account: Pubkey,
+    pub new_fee_reserve_account: Pubkey,
+    pub old_fee_dao_account: Pubkey,
+    pub new_fee_dao_account: Pubkey,
+    pub old_min_subscription: u64,
+    pub new_min_subscription: u64,
+    pub old_max_subscription_per_tx: u64,
+    pub new_max_subscription_per_tx: u64,
+    pub old_max_investor_deposits: u64,
+    pub new_max_investor_deposits: u64,
+    pub old_max_total_deposits: u64,
+    pub new_max_total_deposits: u64,
+    pub old_compliance_authority: Pubkey,
+    pub new_compliance_authority: Pubkey,
+    pub old_require_kyc: bool,
+    pub new_require_kyc: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin proposes a new admin key
+#[event]
+pub struct AdminProposedEvent {
+    pub current_admin: Pubkey,
+    pub proposed_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the proposed admin accepts and takes over
+#[event]
+pub struct AdminTransferredEvent {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin schedules an oracle signer rotation
+#[event]
+pub struct OracleRotationScheduledEvent {
+    pub current_oracle_signer: Pubkey,
+    pub next_oracle_signer: Pubkey,
+    pub activation_slot: u64,
+    pub cutoff_slot: u64,
+    pub scheduled_by: Pubkey,
+}
+
+/// Emitted when the admin reconfigures the oracle quorum set
+#[event]
+pub struct OracleConfigUpdatedEvent {
+    pub oracles: Vec<Pubkey>,
+    pub quorum: u8,
+    pub tolerance_bps: u16,
+    pub round_timeout_secs: i64,
+    pub updated_by: Pubkey,
+}
+
+/// Emitted when the REI mint's Token-2022 metadata is created
+#[event]
+pub struct TokenMetadataInitializedEvent {
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub initialized_by: Pubkey,
+}
+
+/// Emitted when the admin points the REI metadata at a new URI
+#[event]
+pub struct TokenMetadataUriUpdatedEvent {
+    pub mint: Pubkey,
+    pub old_uri: String,
+    pub new_uri: String,
+    pub updated_by: Pubkey,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::events::AdminTransferredEvent;
+use crate::state::Governance;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// Proposed admin - must match governance.pending_admin
+    #[account(
+        constraint = new_admin.key() == governance.pending_admin @ RenewraError::InvalidAuthority
+    )]
+    pub new_admin: Signer<'info>,
+
+    /// Governance account to hand over
+    #[account(
+        mut,
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+    let clock = Clock::get()?;
+    
+    let old_admin = governance.admin_key;
+    let new_admin = ctx.accounts.new_admin.key();
+    
+    // Complete the transfer and clear the pending proposal
+    governance.admin_key = new_admin;
+    governance.pending_admin = Pubkey::default();
+    
+    emit!(AdminTransferredEvent {
+        old_admin,
+        new_admin,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Admin transferred: {} -> {}", old_admin, new_admin);
+    
+    Ok(())
+}
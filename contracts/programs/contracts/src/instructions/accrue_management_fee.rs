@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::errors::RenewraError;
+use crate::events::ManagementFeeAccruedEvent;
+use crate::state::Governance;
+
+#[derive(Accounts)]
+pub struct AccrueManagementFee<'info> {
+    /// Governance account (fee params, accrual clock, mint authority)
+    #[account(
+        mut,
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// REI token mint (fee is minted against its supply)
+    #[account(
+        mut,
+        address = governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    /// Fee recipient's REI token account
+    #[account(
+        mut,
+        constraint = fee_recipient_account.owner == governance.fee_recipient @ RenewraError::InvalidFeeRecipient,
+        constraint = fee_recipient_account.mint == governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub fee_recipient_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<AccrueManagementFee>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let fee_tokens = accrue(
+        &mut accounts.governance,
+        &accounts.reit_mint,
+        &accounts.fee_recipient_account,
+        &accounts.token_program,
+    )?;
+    
+    msg!("Management fee accrued: {} REI", fee_tokens);
+    
+    Ok(())
+}
+
+/// Charge the management fee for whole days since the last accrual by minting
+/// dilutive REI to the fee recipient. Anyone can crank it; subscribe and
+/// settle_redeem also run it first so no day is skipped. Returns tokens minted.
+pub(crate) fn accrue<'info>(
+    governance: &mut Account<'info, Governance>,
+    reit_mint: &InterfaceAccount<'info, Mint>,
+    fee_recipient_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<u64> {
+    let clock = Clock::get()?;
+    let total_supply = reit_mint.supply;
+    
+    // Step 1: Advance the accrual clock and compute the fee for elapsed whole days
+    let fee_tokens = governance.accrue_management_fee(total_supply, clock.unix_timestamp)?;
+    if fee_tokens == 0 {
+        return Ok(0);
+    }
+    
+    // Step 2: Mint fee REI to the recipient using governance PDA as signer
+    let seeds = &[Governance::SEED, &[governance.bump]];
+    let signer_seeds = &[&seeds[..]];
+    
+    let mint_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        MintTo {
+            mint: reit_mint.to_account_info(),
+            to: fee_recipient_account.to_account_info(),
+            authority: governance.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::mint_to(mint_ctx, fee_tokens)?;
+    
+    // Step 3: Emit ManagementFeeAccruedEvent
+    emit!(ManagementFeeAccruedEvent {
+        fee_tokens,
+        total_token_supply: total_supply,
+        management_fee_bps: governance.management_fee_bps,
+        fee_recipient: fee_recipient_account.key(),
+        accrued_until: governance.last_fee_accrual,
+    });
+    
+    Ok(fee_tokens)
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::events::RedeemApprovedEvent;
+use crate::state::{Governance, RedemptionRequest, RedemptionStatus};
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct ApproveRedeem<'info> {
+    /// Fund administrator - must match governance.admin_key
+    #[account(
+        constraint = fund_authority.key() == governance.admin_key @ RenewraError::InvalidAuthority
+    )]
+    pub fund_authority: Signer<'info>,
+
+    /// Governance account to verify authority
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Redemption request PDA to approve
+    #[account(
+        mut,
+        seeds = [RedemptionRequest::SEED, request_id.to_le_bytes().as_ref()],
+        bump = redemption_request.bump
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+}
+
+pub fn handler(ctx: Context<ApproveRedeem>, request_id: u64) -> Result<()> {
+    let request = &mut ctx.accounts.redemption_request;
+    let clock = Clock::get()?;
+    
+    // Step 1: Only pending requests can be approved
+    require!(
+        request.status == RedemptionStatus::Pending,
+        RenewraError::InvalidRedemptionStatus
+    );
+    
+    // Step 2: Mark request as approved
+    request.status = RedemptionStatus::Approved;
+    
+    // Step 3: Emit RedeemApprovedEvent
+    emit!(RedeemApprovedEvent {
+        requester: request.requester,
+        token_amount: request.token_amount,
+        approved_at: clock.unix_timestamp,
+        request_id,
+    });
+    
+    msg!(
+        "Redemption request #{} approved: {} tokens",
+        request_id,
+        request.token_amount
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::RenewraError;
+use crate::events::RedeemCancelledEvent;
+use crate::state::{
+    Governance, RedemptionQueue, RedemptionRequest, RedemptionStatus, UserYield, YieldState,
+};
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct CancelRedeem<'info> {
+    /// Original requester withdrawing a pending redemption
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    /// Governance account (escrow authority)
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Redemption queue PDA tracking open requests
+    #[account(
+        mut,
+        seeds = [RedemptionQueue::SEED],
+        bump = redemption_queue.bump
+    )]
+    pub redemption_queue: Account<'info, RedemptionQueue>,
+
+    /// Redemption request PDA to cancel (closed afterwards, rent returned to requester)
+    #[account(
+        mut,
+        seeds = [RedemptionRequest::SEED, request_id.to_le_bytes().as_ref()],
+        bump = redemption_request.bump,
+        close = requester
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// User's associated REI token account (receives returned tokens)
+    #[account(
+        mut,
+        constraint = user_reit_account.owner == requester.key() @ RenewraError::InvalidAuthority,
+        constraint = user_reit_account.mint == governance.reit_mint @ RenewraError::InvalidReitMint,
+        constraint = governance.is_reit_ata(&user_reit_account) @ RenewraError::InvalidReitAccount
+    )]
+    pub user_reit_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Per-user escrow PDA token account holding the queued tokens
+    #[account(
+        mut,
+        seeds = [RedemptionQueue::ESCROW_SEED, requester.key().as_ref()],
+        bump
+    )]
+    pub redeem_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// REI token mint
+    #[account(
+        seeds = [b"reit_mint"],
+        bump,
+        address = governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    /// Fund-wide reward index and earning supply
+    #[account(
+        mut,
+        seeds = [YieldState::SEED],
+        bump = yield_state.bump
+    )]
+    pub yield_state: Account<'info, YieldState>,
+
+    /// Requester's yield checkpoint (created on first use at the current index)
+    #[account(
+        init_if_needed,
+        payer = requester,
+        space = UserYield::SPACE,
+        seeds = [UserYield::SEED, requester.key().as_ref()],
+        bump
+    )]
+    pub user_yield: Box<Account<'info, UserYield>>,
+
+    /// Token-2022 program (REI mint)
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts: the REI transfer hook's extra accounts (hook program,
+/// ExtraAccountMetaList and its resolved metas), forwarded to the transfer.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelRedeem<'info>>,
+    request_id: u64,
+) -> Result<()> {
+    let redemption_queue = &mut ctx.accounts.redemption_queue;
+    let request = &mut ctx.accounts.redemption_request;
+    let clock = Clock::get()?;
+    
+    // Step 1: Only the original requester can cancel, and only while pending
+    require!(
+        request.requester == ctx.accounts.requester.key(),
+        RenewraError::InvalidAuthority
+    );
+    require!(
+        request.status == RedemptionStatus::Pending,
+        RenewraError::InvalidRedemptionStatus
+    );
+    
+    let token_amount = request.token_amount;
+    
+    // Step 2: Mark request as cancelled before moving tokens
+    request.status = RedemptionStatus::Cancelled;
+    redemption_queue.open_requests = redemption_queue.open_requests.saturating_sub(1);
+    
+    // Step 3: Return escrowed tokens to the user using governance PDA as signer
+    let seeds = &[Governance::SEED, &[ctx.accounts.governance.bump]];
+    let signer_seeds = &[&seeds[..]];
+    
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.redeem_escrow.to_account_info(),
+        ctx.accounts.reit_mint.to_account_info(),
+        ctx.accounts.user_reit_account.to_account_info(),
+        ctx.accounts.governance.to_account_info(),
+        ctx.remaining_accounts,
+        token_amount,
+        ctx.accounts.reit_mint.decimals,
+        signer_seeds,
+    )?;
+    
+    // Step 4: Checkpoint accrued yield; returned tokens earn from now on
+    let yield_state = &mut ctx.accounts.yield_state;
+    let balance_before = ctx.accounts.user_reit_account.amount;
+    let user_yield = &mut ctx.accounts.user_yield;
+    user_yield.open_if_new(ctx.accounts.requester.key(), yield_state.reward_per_token, ctx.bumps.user_yield);
+    user_yield.checkpoint_transfer(
+        yield_state,
+        balance_before,
+        balance_before
+            .checked_add(token_amount)
+            .ok_or(RenewraError::ArithmeticOverflow)?,
+    )?;
+    
+    // Step 5: Emit RedeemCancelledEvent
+    emit!(RedeemCancelledEvent {
+        requester: ctx.accounts.requester.key(),
+        token_amount,
+        cancelled_at: clock.unix_timestamp,
+        request_id,
+    });
+    
+    msg!(
+        "Redemption request #{} cancelled: {} tokens returned",
+        request_id,
+        token_amount
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::RenewraError;
+use crate::instructions::initialize_mint::TRANSFER_HOOK_PROGRAM_ID;
+use crate::state::{Governance, UserYield, YieldState};
+
+/// Seed of the transfer hook PDA that signs checkpoint_transfer
+pub const CHECKPOINT_AUTHORITY_SEED: &[u8] = b"checkpoint_authority";
+
+#[derive(Accounts)]
+pub struct CheckpointTransfer<'info> {
+    /// Transfer hook PDA - proves the call comes from a Token-2022 REI transfer
+    #[account(
+        seeds = [CHECKPOINT_AUTHORITY_SEED],
+        bump,
+        seeds::program = TRANSFER_HOOK_PROGRAM_ID
+    )]
+    pub checkpoint_authority: Signer<'info>,
+
+    /// Governance account (REI mint address)
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Fund-wide reward index and earning supply
+    #[account(
+        mut,
+        seeds = [YieldState::SEED],
+        bump = yield_state.bump
+    )]
+    pub yield_state: Account<'info, YieldState>,
+
+    /// Sender's REI token account (balance after the transfer)
+    #[account(
+        constraint = source_token.mint == governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// Receiver's REI token account (balance after the transfer)
+    #[account(
+        constraint = destination_token.mint == governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: sender's UserYield PDA, may not exist
+    #[account(
+        mut,
+        seeds = [UserYield::SEED, source_token.owner.as_ref()],
+        bump
+    )]
+    pub source_user_yield: UncheckedAccount<'info>,
+
+    /// CHECK: receiver's UserYield PDA, may not exist
+    #[account(
+        mut,
+        seeds = [UserYield::SEED, destination_token.owner.as_ref()],
+        bump
+    )]
+    pub destination_user_yield: UncheckedAccount<'info>,
+}
+
+/// Called by the transfer hook after Token-2022 has moved `amount` REI, so the
+/// token account balances already reflect the transfer.
+pub fn handler(ctx: Context<CheckpointTransfer>, amount: u64) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let source_token = &ctx.accounts.source_token;
+    let destination_token = &ctx.accounts.destination_token;
+    
+    // Step 1: A transfer to the same account changes no balance
+    if source_token.key() == destination_token.key() {
+        return Ok(());
+    }
+    
+    // Step 2: Sender earned on its pre-transfer balance up to now. Only a holder's
+    // associated REI account is tracked; other accounts never earn.
+    let yield_state = &mut ctx.accounts.yield_state;
+    if governance.is_reit_ata(source_token) {
+        checkpoint_holder(
+            &ctx.accounts.source_user_yield,
+            yield_state,
+            source_token
+                .amount
+                .checked_add(amount)
+                .ok_or(RenewraError::ArithmeticOverflow)?,
+            source_token.amount,
+        )?;
+    }
+    
+    // Step 3: Received tokens only earn from now on
+    if governance.is_reit_ata(destination_token) {
+        checkpoint_holder(
+            &ctx.accounts.destination_user_yield,
+            yield_state,
+            destination_token
+                .amount
+                .checked_sub(amount)
+                .ok_or(RenewraError::ArithmeticOverflow)?,
+            destination_token.amount,
+        )?;
+    }
+    
+    Ok(())
+}
+
+/// Checkpoint a holder's UserYield if it exists. Holders without one open it
+/// at the current index later, so they cannot claim earlier distributions.
+fn checkpoint_holder(
+    user_yield_info: &AccountInfo,
+    yield_state: &mut YieldState,
+    balance_before: u64,
+    balance_after: u64,
+) -> Result<()> {
+    if user_yield_info.owner != &crate::ID || user_yield_info.data_is_empty() {
+        return Ok(());
+    }
+    
+    let mut data = user_yield_info.try_borrow_mut_data()?;
+    let mut user_yield = UserYield::try_deserialize(&mut &data[..])?;
+    user_yield.checkpoint_transfer(yield_state, balance_before, balance_after)?;
+    user_yield.try_serialize(&mut &mut data[..])
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::RenewraError;
+use crate::events::YieldClaimedEvent;
+use crate::state::{FeeVault, Governance, UserYield, YieldState};
+
+#[derive(Accounts)]
+pub struct ClaimYield<'info> {
+    /// REI holder claiming accrued yield
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Governance account (treasury authority) and pause check
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump,
+        constraint = !governance.paused @ RenewraError::FundPaused
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Fund-wide reward index and earning supply
+    #[account(
+        mut,
+        seeds = [YieldState::SEED],
+        bump = yield_state.bump
+    )]
+    pub yield_state: Account<'info, YieldState>,
+
+    /// Holder's yield checkpoint
+    #[account(
+        mut,
+        seeds = [UserYield::SEED, holder.key().as_ref()],
+        bump = user_yield.bump
+    )]
+    pub user_yield: Account<'info, UserYield>,
+
+    /// Holder's associated REI token account (current balance for the checkpoint)
+    #[account(
+        constraint = user_reit_account.owner == holder.key() @ RenewraError::InvalidAuthority,
+        constraint = user_reit_account.mint == governance.reit_mint @ RenewraError::InvalidReitMint,
+        constraint = governance.is_reit_ata(&user_reit_account) @ RenewraError::InvalidReitAccount
+    )]
+    pub user_reit_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Holder's USDC token account (receives the payout)
+    #[account(
+        mut,
+        constraint = user_usdc_account.owner == holder.key() @ RenewraError::InvalidAuthority,
+        constraint = user_usdc_account.mint == governance.usdc_mint @ RenewraError::InvalidUsdcMint
+    )]
+    pub user_usdc_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury PDA token account (pays out USDC, authority = governance PDA)
+    #[account(
+        mut,
+        address = governance.treasury @ RenewraError::InvalidTreasury
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Fee vault (earmarked fees are not available for payouts)
+    #[account(
+        seeds = [FeeVault::SEED],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    /// USDC mint (decimals for transfer_checked)
+    #[account(
+        address = governance.usdc_mint @ RenewraError::InvalidUsdcMint
+    )]
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<ClaimYield>) -> Result<()> {
+    let yield_state = &mut ctx.accounts.yield_state;
+    let user_yield = &mut ctx.accounts.user_yield;
+    let clock = Clock::get()?;
+    
+    // Step 1: Checkpoint at the current index and balance
+    user_yield.checkpoint(yield_state, ctx.accounts.user_reit_account.amount)?;
+    
+    let usdc_amount = user_yield.accrued;
+    require!(usdc_amount > 0, RenewraError::InvalidAmount);
+    
+    // Step 2: Clear the accrual before moving funds; the payout may not spend
+    // earmarked fees or yield still owed to other holders
+    user_yield.accrued = 0;
+    yield_state.total_claimed = yield_state
+        .total_claimed
+        .checked_add(usdc_amount)
+        .ok_or(RenewraError::ArithmeticOverflow)?;
+    require!(
+        ctx.accounts
+            .fee_vault
+            .available_liquidity(ctx.accounts.treasury.amount, yield_state)?
+            >= usdc_amount,
+        RenewraError::InsufficientTreasuryFunds
+    );
+    
+    // Step 3: Transfer USDC from treasury to holder using governance PDA as signer
+    let seeds = &[Governance::SEED, &[ctx.accounts.governance.bump]];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.treasury.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+            to: ctx.accounts.user_usdc_account.to_account_info(),
+            authority: ctx.accounts.governance.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, usdc_amount, ctx.accounts.usdc_mint.decimals)?;
+    
+    // Step 4: Emit YieldClaimedEvent
+    emit!(YieldClaimedEvent {
+        holder: ctx.accounts.holder.key(),
+        usdc_amount,
+        reward_per_token: yield_state.reward_per_token,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Yield claimed: {} USDC", usdc_amount);
+    
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::events::OracleConfigUpdatedEvent;
+use crate::state::{Governance, OracleConfig, MAX_ORACLES};
+
+#[derive(Accounts)]
+pub struct ConfigureOracles<'info> {
+    /// Fund administrator - must match governance.admin_key
+    #[account(
+        constraint = fund_authority.key() == governance.admin_key @ RenewraError::InvalidAuthority
+    )]
+    pub fund_authority: Signer<'info>,
+
+    /// Governance account to verify authority
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Oracle set and pending NAV round
+    #[account(
+        mut,
+        seeds = [OracleConfig::SEED],
+        bump = oracle_config.bump
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+}
+
+/// Parameters for configuring the oracle set (an empty set reverts to governance.oracle_signer)
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigureOraclesParams {
+    pub oracles: Vec<Pubkey>,
+    pub quorum: u8,
+    pub tolerance_bps: u16,
+    pub round_timeout_secs: i64,
+}
+
+pub fn handler(ctx: Context<ConfigureOracles>, params: ConfigureOraclesParams) -> Result<()> {
+    let oracle_config = &mut ctx.accounts.oracle_config;
+    let oracle_count = params.oracles.len();
+    
+    // Step 1: Validate the set: bounded size, unique non-default keys, 1 <= M <= N
+    require!(oracle_count <= MAX_ORACLES, RenewraError::InvalidOracleConfig);
+    for (i, oracle) in params.oracles.iter().enumerate() {
+        require!(
+            *oracle != Pubkey::default() && !params.oracles[..i].contains(oracle),
+            RenewraError::InvalidOracleConfig
+        );
+    }
+    if oracle_count > 0 {
+        require!(
+            params.quorum > 0 && params.quorum as usize <= oracle_count,
+            RenewraError::InvalidOracleConfig
+        );
+    }
+    require!(
+        params.tolerance_bps <= 10_000 && params.round_timeout_secs > 0,
+        RenewraError::InvalidOracleConfig
+    );
+    
+    // Step 2: Store the new set
+    let mut oracles = [Pubkey::default(); MAX_ORACLES];
+    oracles[..oracle_count].copy_from_slice(&params.oracles);
+    oracle_config.oracles = oracles;
+    oracle_config.oracle_count = oracle_count as u8;
+    oracle_config.quorum = if oracle_count > 0 { params.quorum } else { 1 };
+    oracle_config.tolerance_bps = params.tolerance_bps;
+    oracle_config.round_timeout_secs = params.round_timeout_secs;
+    
+    // Step 3: Submissions from the previous set no longer count
+    oracle_config.reset_round();
+    
+    // Step 4: Emit OracleConfigUpdatedEvent
+    emit!(OracleConfigUpdatedEvent {
+        oracles: params.oracles,
+        quorum: oracle_config.quorum,
+        tolerance_bps: params.tolerance_bps,
+        round_timeout_secs: params.round_timeout_secs,
+        updated_by: ctx.accounts.fund_authority.key(),
+    });
+    
+    msg!(
+        "Oracle set updated: {}-of-{} (tolerance {} bps)",
+        oracle_config.quorum,
+        oracle_count,
+        params.tolerance_bps
+    );
+    
+    Ok(())
+}
@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::RenewraError;
 use crate::events::DistributeYieldEvent;
-use crate::state::Governance;
+use crate::state::{FeeVault, Governance, NavHistory, NavOracle, YieldMode, YieldState};
 
 #[derive(Accounts)]
 pub struct DistributeYield<'info> {
@@ -22,55 +22,144 @@ pub struct DistributeYield<'info> {
     pub governance: Account<'info, Governance>,
 
     /// Treasury PDA token account holding USDC (for balance verification)
-    #[account(mut)]
-    pub treasury: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = governance.treasury @ RenewraError::InvalidTreasury
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
 
     /// REI token mint to read total supply
-    pub reit_mint: Account<'info, Mint>,
+    #[account(address = governance.reit_mint @ RenewraError::InvalidReitMint)]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
 
-    pub token_program: Program<'info, Token>,
+    /// Fund-wide reward index raised by each distribution
+    #[account(
+        mut,
+        seeds = [YieldState::SEED],
+        bump = yield_state.bump
+    )]
+    pub yield_state: Account<'info, YieldState>,
+
+    /// Fee vault (earmarked fees cannot back a distribution)
+    #[account(
+        seeds = [FeeVault::SEED],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    /// NAV oracle (raised in reinvest mode)
+    #[account(
+        mut,
+        seeds = [NavOracle::SEED],
+        bump = nav_oracle.bump,
+        constraint = nav_oracle.latest_nav > 0 @ RenewraError::InvalidNavPrice
+    )]
+    pub nav_oracle: Account<'info, NavOracle>,
+
+    /// NAV history (reinvest uplifts are checkpointed for the TWAP)
+    #[account(
+        mut,
+        seeds = [NavHistory::SEED],
+        bump = nav_history.load()?.bump
+    )]
+    pub nav_history: AccountLoader<'info, NavHistory>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handler(ctx: Context<DistributeYield>, yield_amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<DistributeYield>, yield_amount: u64, mode: YieldMode) -> Result<()> {
     // Validate yield amount
     require!(yield_amount > 0, RenewraError::InvalidAmount);
     
     let treasury = &ctx.accounts.treasury;
     let reit_mint = &ctx.accounts.reit_mint;
     
-    // Defensive check: treasury has sufficient USDC
+    // Defensive check: treasury has sufficient USDC beyond earmarked fees and
+    // yield already owed to holders
     require!(
-        treasury.amount >= yield_amount,
+        ctx.accounts
+            .fee_vault
+            .available_liquidity(treasury.amount, &ctx.accounts.yield_state)?
+            >= yield_amount,
         RenewraError::InsufficientTokens
     );
     
-    // Step 1: Read total REI token supply
-    let total_supply = reit_mint.supply;
+    // Step 1: Read the REI supply sharing the yield. Cash goes only to holders with a
+    // yield checkpoint (escrowed and fee-recipient REI can never claim it); a NAV
+    // uplift applies to every token in circulation.
+    let total_supply = match mode {
+        YieldMode::Cash => ctx.accounts.yield_state.earning_supply,
+        YieldMode::Reinvest => reit_mint.supply,
+    };
     require!(total_supply > 0, RenewraError::InvalidAmount);
     
-    // Step 2: Calculate per-token yield using u128 for precision
+    // Step 2: Calculate per-token yield using u128 for precision, rounded down
     // Formula: per_token = (yield_amount * 10^6) / total_supply
-    // This gives yield in micro-units per token
+    // This gives USDC micro-units per whole token (same fixed point as the NAV)
     let per_token_yield = (yield_amount as u128)
-        .checked_mul(1_000_000)
+        .checked_mul(NavOracle::NAV_SCALE as u128)
         .ok_or(RenewraError::ArithmeticOverflow)?
         .checked_div(total_supply as u128)
         .ok_or(RenewraError::ArithmeticOverflow)?;
     
-    // Step 3: For MVP hackathon - emit event only, no transfers
-    // In production, would iterate through holder accounts and transfer
-    // Frontend/oracle engine uses this event to verify distribution
+    let yield_state = &mut ctx.accounts.yield_state;
+    let nav_oracle = &mut ctx.accounts.nav_oracle;
+    
+    match mode {
+        // Step 3a: Raise the reward index; USDC stays in the treasury until holders claim_yield
+        YieldMode::Cash => {
+            yield_state.distribute(yield_amount)?;
+        }
+        // Step 3b: Keep USDC in the treasury and raise NAV by the per-token yield,
+        // bounded by the same deviation band that applies to oracle updates.
+        // The oracle keeps its freshness timestamp; the oracle service adds
+        // total_reinvested to fund cash so the next submission carries the uplift.
+        // The step is checkpointed in NAV history so the TWAP weights it correctly.
+        YieldMode::Reinvest => {
+            let uplift = u64::try_from(per_token_yield).map_err(|_| RenewraError::ArithmeticOverflow)?;
+            require!(uplift > 0, RenewraError::InvalidAmount);
+            
+            let new_nav = nav_oracle
+                .latest_nav
+                .checked_add(uplift)
+                .ok_or(RenewraError::ArithmeticOverflow)?;
+            require!(
+                nav_oracle.deviation_bps(new_nav) <= ctx.accounts.governance.max_nav_deviation_bps as u64,
+                RenewraError::NavDeviationTooLarge
+            );
+            
+            let now = Clock::get()?.unix_timestamp;
+            nav_oracle.apply_reinvest(new_nav, now)?;
+            ctx.accounts.nav_history.load_mut()?.push(
+                new_nav,
+                now,
+                ctx.accounts.fund_authority.key(),
+                nav_oracle.cumulative_nav_seconds,
+            );
+            
+            yield_state.total_reinvested = yield_state
+                .total_reinvested
+                .checked_add(yield_amount)
+                .ok_or(RenewraError::ArithmeticOverflow)?;
+        }
+    }
+    
+    // Step 4: Emit DistributeYieldEvent
     emit!(DistributeYieldEvent {
         yield_amount,
         total_token_supply: total_supply,
         per_token_yield,
+        reward_per_token: yield_state.reward_per_token,
+        mode,
+        nav_after: nav_oracle.latest_nav,
     });
     
     msg!(
-        "Yield distribution recorded: {} USDC across {} tokens ({} per token)",
+        "Yield distribution recorded: {} USDC across {} tokens ({} per token), NAV {}",
         yield_amount,
         total_supply,
-        per_token_yield
+        per_token_yield,
+        nav_oracle.latest_nav
     );
     
     Ok(())
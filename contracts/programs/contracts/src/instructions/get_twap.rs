@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::state::{NavHistory, NavOracle};
+
+#[derive(Accounts)]
+pub struct GetTwap<'info> {
+    /// NAV oracle holding the TWAP accumulator
+    #[account(
+        seeds = [NavOracle::SEED],
+        bump = nav_oracle.bump
+    )]
+    pub nav_oracle: Account<'info, NavOracle>,
+
+    /// NAV history checkpoints
+    #[account(
+        seeds = [NavHistory::SEED],
+        bump = nav_history.load()?.bump
+    )]
+    pub nav_history: AccountLoader<'info, NavHistory>,
+}
+
+pub fn handler(ctx: Context<GetTwap>, window_secs: i64) -> Result<u64> {
+    require!(window_secs > 0, RenewraError::InvalidAmount);
+    
+    let clock = Clock::get()?;
+    let twap = ctx
+        .accounts
+        .nav_oracle
+        .twap(&*ctx.accounts.nav_history.load()?, clock.unix_timestamp, window_secs)?;
+    
+    msg!("TWAP over {}s: {}", window_secs, twap);
+    
+    Ok(twap)
+}
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::state::{Governance, NavOracle, RedemptionQueue};
+use crate::errors::RenewraError;
+use crate::state::{
+    FeeVault, Governance, NavHistory, NavOracle, NavSubmission, OracleConfig, PricingMode,
+    RedemptionQueue, YieldState, MAX_ORACLES,
+};
 
 #[derive(Accounts)]
 pub struct InitializeFund<'info> {
@@ -25,6 +30,24 @@ pub struct InitializeFund<'info> {
     )]
     pub nav_oracle: Account<'info, NavOracle>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = NavHistory::SPACE,
+        seeds = [NavHistory::SEED],
+        bump
+    )]
+    pub nav_history: AccountLoader<'info, NavHistory>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = OracleConfig::SPACE,
+        seeds = [OracleConfig::SEED],
+        bump
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
     #[account(
         init,
         payer = authority,
@@ -34,6 +57,41 @@ pub struct InitializeFund<'info> {
     )]
     pub redemption_queue: Account<'info, RedemptionQueue>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = YieldState::SPACE,
+        seeds = [YieldState::SEED],
+        bump
+    )]
+    pub yield_state: Account<'info, YieldState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = FeeVault::SPACE,
+        seeds = [FeeVault::SEED],
+        bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    /// USDC mint accepted by the fund
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Treasury PDA token account holding fund USDC (authority = governance PDA)
+    #[account(
+        init,
+        payer = authority,
+        seeds = [Governance::TREASURY_SEED],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = governance,
+        token::token_program = token_program,
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the USDC mint (SPL Token or Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -44,12 +102,22 @@ pub struct InitializeFundParams {
     pub management_fee_bps: u16,
     pub mint_fee_bps: u16,
     pub redemption_fee_bps: u16,
+    /// Initial NAV per token with NavOracle::NAV_DECIMALS (e.g., 50_000_000 = $50.00)
     pub initial_nav: u64,
+    pub guardian: Pubkey,
 }
 
 pub fn handler(ctx: Context<InitializeFund>, params: InitializeFundParams) -> Result<()> {
     msg!("Initializing Renewra fund...");
     
+    // Validate fee parameters against the hard cap
+    require!(
+        params.management_fee_bps <= Governance::MAX_FEE_BPS
+            && params.mint_fee_bps <= Governance::MAX_FEE_BPS
+            && params.redemption_fee_bps <= Governance::MAX_FEE_BPS,
+        RenewraError::FeeTooHigh
+    );
+    
     // Initialize Governance
     let governance = &mut ctx.accounts.governance;
     governance.admin_key = ctx.accounts.authority.key();
@@ -58,6 +126,25 @@ pub fn handler(ctx: Context<InitializeFund>, params: InitializeFundParams) -> Re
     governance.mint_fee_bps = params.mint_fee_bps;
     governance.redemption_fee_bps = params.redemption_fee_bps;
     governance.paused = false;
+    governance.guardian = params.guardian;
+    governance.pending_admin = Pubkey::default();
+    governance.next_oracle_signer = Pubkey::default();
+    governance.oracle_activation_slot = 0;
+    governance.oracle_overlap_slots = 0;
+    governance.max_nav_deviation_bps = Governance::DEFAULT_MAX_NAV_DEVIATION_BPS;
+    governance.nav_deviation_auto_pause = false;
+    governance.max_nav_age_secs = Governance::DEFAULT_MAX_NAV_AGE_SECS;
+    governance.pricing_mode = PricingMode::Spot;
+    governance.twap_window_secs = Governance::DEFAULT_TWAP_WINDOW_SECS;
+    governance.treasury = ctx.accounts.treasury.key();
+    governance.usdc_mint = ctx.accounts.usdc_mint.key();
+    governance.fee_recipient = ctx.accounts.authority.key();
+    governance.last_fee_accrual = Clock::get()?.unix_timestamp;
+    governance.fee_split_operations_bps = Governance::DEFAULT_FEE_SPLIT_OPERATIONS_BPS;
+    governance.fee_split_reserve_bps = Governance::DEFAULT_FEE_SPLIT_RESERVE_BPS;
+    governance.fee_split_dao_bps = Governance::DEFAULT_FEE_SPLIT_DAO_BPS;
+    governance.compliance_authority = ctx.accounts.authority.key();
+    governance.require_kyc = false;
     governance.bump = ctx.bumps.governance;
 
     // Initialize NavOracle
@@ -65,13 +152,58 @@ pub fn handler(ctx: Context<InitializeFund>, params: InitializeFundParams) -> Re
     nav_oracle.latest_nav = params.initial_nav;
     nav_oracle.previous_nav = params.initial_nav;
     nav_oracle.timestamp = Clock::get()?.unix_timestamp;
+    nav_oracle.cumulative_nav_seconds = 0;
+    nav_oracle.cumulative_timestamp = nav_oracle.timestamp;
     nav_oracle.bump = ctx.bumps.nav_oracle;
 
+    // Initialize NavHistory with the initial NAV as its first entry
+    let mut nav_history = ctx.accounts.nav_history.load_init()?;
+    nav_history.bump = ctx.bumps.nav_history;
+    nav_history.push(
+        params.initial_nav,
+        nav_oracle.timestamp,
+        ctx.accounts.authority.key(),
+        nav_oracle.cumulative_nav_seconds,
+    );
+    drop(nav_history);
+
+    // Initialize OracleConfig (empty set = single governance.oracle_signer)
+    let oracle_config = &mut ctx.accounts.oracle_config;
+    oracle_config.oracles = [Pubkey::default(); MAX_ORACLES];
+    oracle_config.oracle_count = 0;
+    oracle_config.quorum = 1;
+    oracle_config.tolerance_bps = OracleConfig::DEFAULT_TOLERANCE_BPS;
+    oracle_config.round_timeout_secs = OracleConfig::DEFAULT_ROUND_TIMEOUT_SECS;
+    oracle_config.round_id = 1;
+    oracle_config.round_opened_at = 0;
+    oracle_config.submissions = [NavSubmission::default(); MAX_ORACLES];
+    oracle_config.submission_count = 0;
+    oracle_config.bump = ctx.bumps.oracle_config;
+
     // Initialize RedemptionQueue
     let redemption_queue = &mut ctx.accounts.redemption_queue;
-    redemption_queue.requests = Vec::new();
+    redemption_queue.next_request_id = 1;
+    redemption_queue.open_requests = 0;
     redemption_queue.bump = ctx.bumps.redemption_queue;
 
+    // Initialize YieldState with an empty reward index
+    let yield_state = &mut ctx.accounts.yield_state;
+    yield_state.reward_per_token = 0;
+    yield_state.total_distributed = 0;
+    yield_state.total_claimed = 0;
+    yield_state.total_reinvested = 0;
+    yield_state.earning_supply = 0;
+    yield_state.bump = ctx.bumps.yield_state;
+
+    // Initialize FeeVault with empty buckets (destinations are set via update_governance)
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    fee_vault.operations_pending = 0;
+    fee_vault.reserve_pending = 0;
+    fee_vault.dao_pending = 0;
+    fee_vault.total_collected = 0;
+    fee_vault.total_swept = 0;
+    fee_vault.bump = ctx.bumps.fee_vault;
+
     msg!("Renewra fund initialized successfully!");
     Ok(())
 }
@@ -1,21 +1,28 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::Mint;
 
 use crate::state::Governance;
 
+/// Renewra transfer hook program enforcing the investor allowlist on REI transfers
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey =
+    pubkey!("3TtFMPAxzR1DDC4vymM9xfhmAh5Ktp8fs4fhGpd4hZFo");
+
 #[derive(Accounts)]
 pub struct InitializeMint<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    /// Governance account (will be mint authority)
+    /// Governance account (will be mint authority, records the mint address)
     #[account(
+        mut,
         seeds = [Governance::SEED],
         bump = governance.bump,
     )]
     pub governance: Account<'info, Governance>,
 
-    /// REI token mint to be created
+    /// REI Token-2022 mint to be created, with a metadata pointer to itself and
+    /// the Renewra transfer hook
     #[account(
         init,
         payer = authority,
@@ -23,16 +30,28 @@ pub struct InitializeMint<'info> {
         bump,
         mint::decimals = 6,
         mint::authority = governance,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = governance,
+        extensions::metadata_pointer::metadata_address = reit_mint,
+        extensions::transfer_hook::authority = governance,
+        extensions::transfer_hook::program_id = transfer_hook_program,
     )]
-    pub reit_mint: Account<'info, Mint>,
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Renewra transfer hook program, pinned by address
+    #[account(address = TRANSFER_HOOK_PROGRAM_ID)]
+    pub transfer_hook_program: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<InitializeMint>) -> Result<()> {
+    ctx.accounts.governance.reit_mint = ctx.accounts.reit_mint.key();
+    
     msg!("REIT mint initialized successfully!");
     msg!("Mint: {}", ctx.accounts.reit_mint.key());
     msg!("Authority: {}", ctx.accounts.governance.key());
+    msg!("Transfer hook: {}", ctx.accounts.transfer_hook_program.key());
     Ok(())
 }
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022_extensions::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::TokenMetadata;
+use anchor_spl::token_2022_extensions::{token_metadata_initialize, TokenMetadataInitialize};
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::RenewraError;
+use crate::events::TokenMetadataInitializedEvent;
+use crate::state::Governance;
+
+/// REI token name shown by wallets and explorers
+pub const REI_NAME: &str = "Renewable Energy Index Token";
+
+/// REI token symbol
+pub const REI_SYMBOL: &str = "REI";
+
+/// Maximum metadata URI length (Metaplex convention)
+pub const MAX_URI_LEN: usize = 200;
+
+#[derive(Accounts)]
+pub struct InitializeTokenMetadata<'info> {
+    /// Fund administrator - must match governance.admin_key (pays the mint rent top-up)
+    #[account(
+        mut,
+        constraint = fund_authority.key() == governance.admin_key @ RenewraError::InvalidAuthority
+    )]
+    pub fund_authority: Signer<'info>,
+
+    /// Governance account (mint authority and metadata update authority)
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// REI token mint (metadata pointer targets the mint itself)
+    #[account(
+        mut,
+        address = governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTokenMetadata>, uri: String) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let mint_info = ctx.accounts.reit_mint.to_account_info();
+    
+    // Step 1: Validate the URI
+    validate_uri(&uri)?;
+    
+    // Step 2: Fund the mint for the metadata entry Token-2022 appends to it
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(governance.key()))?,
+        mint: mint_info.key(),
+        name: REI_NAME.to_string(),
+        symbol: REI_SYMBOL.to_string(),
+        uri: uri.clone(),
+        additional_metadata: vec![],
+    };
+    let new_len = mint_info
+        .data_len()
+        .checked_add(metadata.tlv_size_of()?)
+        .ok_or(RenewraError::ArithmeticOverflow)?;
+    top_up_mint_rent(
+        &ctx.accounts.fund_authority,
+        &mint_info,
+        &ctx.accounts.system_program,
+        new_len,
+    )?;
+    
+    // Step 3: Write the metadata using governance PDA as mint and update authority
+    let seeds = &[Governance::SEED, &[governance.bump]];
+    let signer_seeds = &[&seeds[..]];
+    
+    let metadata_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TokenMetadataInitialize {
+            token_program_id: ctx.accounts.token_program.to_account_info(),
+            metadata: mint_info.clone(),
+            update_authority: governance.to_account_info(),
+            mint_authority: governance.to_account_info(),
+            mint: mint_info.clone(),
+        },
+        signer_seeds,
+    );
+    token_metadata_initialize(
+        metadata_ctx,
+        REI_NAME.to_string(),
+        REI_SYMBOL.to_string(),
+        uri.clone(),
+    )?;
+    
+    // Step 4: Emit TokenMetadataInitializedEvent
+    emit!(TokenMetadataInitializedEvent {
+        mint: mint_info.key(),
+        name: REI_NAME.to_string(),
+        symbol: REI_SYMBOL.to_string(),
+        uri: uri.clone(),
+        initialized_by: ctx.accounts.fund_authority.key(),
+    });
+    
+    msg!("Token metadata initialized: {} ({}) -> {}", REI_NAME, REI_SYMBOL, uri);
+    
+    Ok(())
+}
+
+/// Reject empty URIs and URIs longer than MAX_URI_LEN
+pub(crate) fn validate_uri(uri: &str) -> Result<()> {
+    require!(
+        !uri.is_empty() && uri.len() <= MAX_URI_LEN,
+        RenewraError::InvalidMetadataUri
+    );
+    Ok(())
+}
+
+/// Token-2022 reallocs the mint when metadata grows but does not fund it, so
+/// transfer the rent-exempt shortfall for `new_len` bytes from the payer first.
+pub(crate) fn top_up_mint_rent<'info>(
+    payer: &Signer<'info>,
+    mint_info: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(mint_info.lamports());
+    if shortfall == 0 {
+        return Ok(());
+    }
+    
+    let transfer_ctx = CpiContext::new(
+        system_program.to_account_info(),
+        Transfer {
+            from: payer.to_account_info(),
+            to: mint_info.clone(),
+        },
+    );
+    system_program::transfer(transfer_ctx, shortfall)
+}
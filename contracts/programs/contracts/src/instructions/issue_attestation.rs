@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::events::AttestationIssuedEvent;
+use crate::state::{Governance, InvestorAttestation};
+
+#[derive(Accounts)]
+#[instruction(params: IssueAttestationParams)]
+pub struct IssueAttestation<'info> {
+    /// Compliance authority - must match governance.compliance_authority
+    #[account(
+        mut,
+        constraint = compliance_authority.key() == governance.compliance_authority @ RenewraError::InvalidAuthority
+    )]
+    pub compliance_authority: Signer<'info>,
+
+    /// Governance account holding the compliance authority key
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Investor attestation PDA (created on first issue, overwritten on renewal)
+    #[account(
+        init_if_needed,
+        payer = compliance_authority,
+        space = InvestorAttestation::SPACE,
+        seeds = [InvestorAttestation::SEED, params.investor.as_ref()],
+        bump
+    )]
+    pub investor_attestation: Account<'info, InvestorAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for issuing or renewing an investor attestation
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct IssueAttestationParams {
+    /// Investor wallet being attested
+    pub investor: Pubkey,
+    /// ISO 3166-1 alpha-2 jurisdiction code (uppercase ASCII, e.g. b"US")
+    pub jurisdiction: [u8; 2],
+    /// Unix timestamp after which the attestation lapses
+    pub expires_at: i64,
+}
+
+pub fn handler(ctx: Context<IssueAttestation>, params: IssueAttestationParams) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    // Step 1: Validate expiry and jurisdiction code
+    require!(
+        params.expires_at > clock.unix_timestamp,
+        RenewraError::InvalidAttestation
+    );
+    require!(
+        params.jurisdiction.iter().all(u8::is_ascii_uppercase),
+        RenewraError::InvalidAttestation
+    );
+    
+    // Step 2: Write the attestation (renewal clears any previous revocation)
+    let attestation = &mut ctx.accounts.investor_attestation;
+    attestation.investor = params.investor;
+    attestation.jurisdiction = params.jurisdiction;
+    attestation.issued_at = clock.unix_timestamp;
+    attestation.expires_at = params.expires_at;
+    attestation.revoked = false;
+    attestation.issued_by = ctx.accounts.compliance_authority.key();
+    attestation.bump = ctx.bumps.investor_attestation;
+    
+    // Step 3: Emit AttestationIssuedEvent
+    emit!(AttestationIssuedEvent {
+        investor: params.investor,
+        jurisdiction: params.jurisdiction,
+        expires_at: params.expires_at,
+        issued_by: ctx.accounts.compliance_authority.key(),
+    });
+    
+    msg!("Attestation issued for {} until {}", params.investor, params.expires_at);
+    
+    Ok(())
+}
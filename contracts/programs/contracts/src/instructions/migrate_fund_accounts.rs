@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::state::{FeeVault, Governance, NavSubmission, OracleConfig, YieldState, MAX_ORACLES};
+
+/// Last step of upgrading a baseline deployment (after migrate_governance): create
+/// the PDAs the baseline program did not have, with their initialize_fund defaults
+#[derive(Accounts)]
+pub struct MigrateFundAccounts<'info> {
+    /// Fund administrator - must match governance.admin_key (pays rent)
+    #[account(
+        mut,
+        constraint = fund_authority.key() == governance.admin_key @ RenewraError::InvalidAuthority
+    )]
+    pub fund_authority: Signer<'info>,
+
+    /// Governance account, already in the current layout
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = fund_authority,
+        space = OracleConfig::SPACE,
+        seeds = [OracleConfig::SEED],
+        bump
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        init,
+        payer = fund_authority,
+        space = YieldState::SPACE,
+        seeds = [YieldState::SEED],
+        bump
+    )]
+    pub yield_state: Box<Account<'info, YieldState>>,
+
+    #[account(
+        init,
+        payer = fund_authority,
+        space = FeeVault::SPACE,
+        seeds = [FeeVault::SEED],
+        bump
+    )]
+    pub fee_vault: Box<Account<'info, FeeVault>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateFundAccounts>) -> Result<()> {
+    // Step 1: Empty oracle set = single governance.oracle_signer
+    let oracle_config = &mut ctx.accounts.oracle_config;
+    oracle_config.oracles = [Pubkey::default(); MAX_ORACLES];
+    oracle_config.oracle_count = 0;
+    oracle_config.quorum = 1;
+    oracle_config.tolerance_bps = OracleConfig::DEFAULT_TOLERANCE_BPS;
+    oracle_config.round_timeout_secs = OracleConfig::DEFAULT_ROUND_TIMEOUT_SECS;
+    oracle_config.round_id = 1;
+    oracle_config.round_opened_at = 0;
+    oracle_config.submissions = [NavSubmission::default(); MAX_ORACLES];
+    oracle_config.submission_count = 0;
+    oracle_config.bump = ctx.bumps.oracle_config;
+    
+    // Step 2: Empty reward index; holders are recorded as they next interact
+    let yield_state = &mut ctx.accounts.yield_state;
+    yield_state.reward_per_token = 0;
+    yield_state.total_distributed = 0;
+    yield_state.total_claimed = 0;
+    yield_state.total_reinvested = 0;
+    yield_state.earning_supply = 0;
+    yield_state.bump = ctx.bumps.yield_state;
+    
+    // Step 3: Empty fee buckets (destinations are set via update_governance)
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    fee_vault.operations_pending = 0;
+    fee_vault.reserve_pending = 0;
+    fee_vault.dao_pending = 0;
+    fee_vault.total_collected = 0;
+    fee_vault.total_swept = 0;
+    fee_vault.bump = ctx.bumps.fee_vault;
+    
+    msg!("Oracle config, yield state and fee vault created for the migrated fund");
+    
+    Ok(())
+}
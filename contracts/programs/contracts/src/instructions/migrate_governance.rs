@@ -0,0 +1,388 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::RenewraError;
+use crate::state::{Governance, PricingMode, RedemptionQueue};
+
+/// First step of upgrading a baseline deployment. The REI mint is kept as it is:
+/// a baseline mint is a classic SPL Token mint without the KYC transfer hook or
+/// metadata, so on such a fund REI moves between wallets without attestation checks
+/// or yield checkpoints (holders only earn on balances the program has recorded).
+/// Moving holders to a Token-2022 mint is a separate token migration.
+#[derive(Accounts)]
+pub struct MigrateGovernance<'info> {
+    /// Fund administrator - must match the legacy governance admin_key (pays rent)
+    #[account(mut)]
+    pub fund_authority: Signer<'info>,
+
+    /// CHECK: governance PDA in the baseline 80-byte layout; checked, grown and
+    /// rewritten in the current layout by the handler
+    #[account(
+        mut,
+        seeds = [Governance::SEED],
+        bump
+    )]
+    pub governance: UncheckedAccount<'info>,
+
+    /// CHECK: redemption queue PDA in the baseline Vec layout; checked and rewritten
+    /// as the request-id counter by the handler
+    #[account(
+        mut,
+        seeds = [RedemptionQueue::SEED],
+        bump
+    )]
+    pub redemption_queue: UncheckedAccount<'info>,
+
+    /// Existing REI mint created by the baseline initialize_mint
+    #[account(
+        seeds = [b"reit_mint"],
+        bump
+    )]
+    pub reit_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// USDC mint accepted by the fund
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Treasury PDA token account holding fund USDC (authority = governance PDA)
+    #[account(
+        init,
+        payer = fund_authority,
+        seeds = [Governance::TREASURY_SEED],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = governance,
+        token::token_program = token_program,
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Governance-owned USDC account the baseline fund used as its treasury, if any;
+    /// its balance moves into the treasury PDA
+    #[account(
+        mut,
+        constraint = legacy_treasury.owner == governance.key() @ RenewraError::InvalidTreasury,
+        constraint = legacy_treasury.mint == usdc_mint.key() @ RenewraError::InvalidUsdcMint
+    )]
+    pub legacy_treasury: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Token program owning the USDC mint (SPL Token or Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Baseline Governance layout: 8 (discriminator) + 32 + 32 + 2 + 2 + 2 + 1 + 1 = 80 bytes
+#[derive(AnchorDeserialize)]
+struct LegacyGovernance {
+    admin_key: Pubkey,
+    oracle_signer: Pubkey,
+    management_fee_bps: u16,
+    mint_fee_bps: u16,
+    redemption_fee_bps: u16,
+    paused: bool,
+    bump: u8,
+}
+
+impl LegacyGovernance {
+    const SPACE: usize = 80;
+
+    /// Current-layout governance carrying over the legacy keys, fees, pause flag
+    /// and bump, with every newer parameter at its initialize_fund default
+    fn upgrade(
+        self,
+        treasury: Pubkey,
+        usdc_mint: Pubkey,
+        reit_mint: Pubkey,
+        total_deposits: u64,
+        now: i64,
+    ) -> Governance {
+        Governance {
+            admin_key: self.admin_key,
+            oracle_signer: self.oracle_signer,
+            management_fee_bps: self.management_fee_bps,
+            mint_fee_bps: self.mint_fee_bps,
+            redemption_fee_bps: self.redemption_fee_bps,
+            paused: self.paused,
+            guardian: self.admin_key,
+            pending_admin: Pubkey::default(),
+            next_oracle_signer: Pubkey::default(),
+            oracle_activation_slot: 0,
+            oracle_overlap_slots: 0,
+            max_nav_deviation_bps: Governance::DEFAULT_MAX_NAV_DEVIATION_BPS,
+            nav_deviation_auto_pause: false,
+            max_nav_age_secs: Governance::DEFAULT_MAX_NAV_AGE_SECS,
+            pricing_mode: PricingMode::Spot,
+            twap_window_secs: Governance::DEFAULT_TWAP_WINDOW_SECS,
+            treasury,
+            usdc_mint,
+            reit_mint,
+            fee_recipient: self.admin_key,
+            last_fee_accrual: now,
+            fee_split_operations_bps: Governance::DEFAULT_FEE_SPLIT_OPERATIONS_BPS,
+            fee_split_reserve_bps: Governance::DEFAULT_FEE_SPLIT_RESERVE_BPS,
+            fee_split_dao_bps: Governance::DEFAULT_FEE_SPLIT_DAO_BPS,
+            fee_operations_account: Pubkey::default(),
+            fee_reserve_account: Pubkey::default(),
+            fee_dao_account: Pubkey::default(),
+            min_subscription: 0,
+            max_subscription_per_tx: 0,
+            max_investor_deposits: 0,
+            max_total_deposits: 0,
+            total_deposits,
+            compliance_authority: self.admin_key,
+            require_kyc: false,
+            bump: self.bump,
+        }
+    }
+}
+
+/// Baseline queued request
+#[derive(AnchorDeserialize)]
+struct LegacyRedemptionRequest {
+    _requester: Pubkey,
+    _token_amount: u64,
+    _requested_at: i64,
+    status: u8,
+}
+
+impl LegacyRedemptionRequest {
+    /// Baseline RedemptionStatus::Settled
+    const SETTLED: u8 = 2;
+}
+
+/// Baseline RedemptionQueue layout: 8 + 4 (vec len) + 49 * 100 + 1 = 4913 bytes
+#[derive(AnchorDeserialize)]
+struct LegacyRedemptionQueue {
+    requests: Vec<LegacyRedemptionRequest>,
+    bump: u8,
+}
+
+impl LegacyRedemptionQueue {
+    const SPACE: usize = 4913;
+
+    /// Request-id counter continuing after the legacy ids. Fails while any legacy
+    /// request is still open: it has no escrow or request PDA to carry it over, so
+    /// it would otherwise be dropped without its holder being paid or told.
+    fn upgrade(self) -> Result<RedemptionQueue> {
+        require!(
+            self.requests
+                .iter()
+                .all(|request| request.status == LegacyRedemptionRequest::SETTLED),
+            RenewraError::LegacyRedemptionsPending
+        );
+
+        Ok(RedemptionQueue {
+            next_request_id: self.requests.len() as u64 + 1,
+            open_requests: 0,
+            bump: self.bump,
+        })
+    }
+}
+
+/// Deserialize a baseline-layout account, checking its exact size and discriminator
+pub(crate) fn unpack_legacy<T: AnchorDeserialize>(
+    data: &[u8],
+    discriminator: &[u8],
+    space: usize,
+) -> Result<T> {
+    require!(
+        data.len() == space && data.starts_with(discriminator),
+        RenewraError::InvalidLegacyAccount
+    );
+    T::deserialize(&mut &data[8..]).map_err(|_| RenewraError::InvalidLegacyAccount.into())
+}
+
+/// Realloc `info` to `space` bytes, topping up rent from `payer`
+pub(crate) fn grow_account<'info>(
+    info: &AccountInfo<'info>,
+    space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent_due = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(info.lamports());
+    if rent_due > 0 {
+        let transfer_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: info.clone(),
+            },
+        );
+        system_program::transfer(transfer_ctx, rent_due)?;
+    }
+    info.realloc(space, true)?;
+    Ok(())
+}
+
+pub fn handler(ctx: Context<MigrateGovernance>) -> Result<()> {
+    let governance_info = ctx.accounts.governance.to_account_info();
+    let queue_info = ctx.accounts.redemption_queue.to_account_info();
+    
+    // Step 1: Read the baseline accounts and check the signer is the legacy admin
+    let legacy_governance: LegacyGovernance = unpack_legacy(
+        &governance_info.try_borrow_data()?,
+        &Governance::DISCRIMINATOR,
+        LegacyGovernance::SPACE,
+    )?;
+    require_keys_eq!(
+        legacy_governance.admin_key,
+        ctx.accounts.fund_authority.key(),
+        RenewraError::InvalidAuthority
+    );
+    let legacy_queue: LegacyRedemptionQueue = unpack_legacy(
+        &queue_info.try_borrow_data()?,
+        &RedemptionQueue::DISCRIMINATOR,
+        LegacyRedemptionQueue::SPACE,
+    )?;
+    let redemption_queue = legacy_queue.upgrade()?;
+    
+    // Step 2: Move the legacy treasury balance into the treasury PDA
+    let mut migrated_usdc = 0;
+    if let Some(legacy_treasury) = &ctx.accounts.legacy_treasury {
+        migrated_usdc = legacy_treasury.amount;
+        
+        let seeds = &[Governance::SEED, &[legacy_governance.bump]];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: legacy_treasury.to_account_info(),
+                mint: ctx.accounts.usdc_mint.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+                authority: governance_info.clone(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, migrated_usdc, ctx.accounts.usdc_mint.decimals)?;
+    }
+    
+    // Step 3: Rewrite governance in the current layout (migrated USDC seeds the
+    // deposit counter) and the queue as the request-id counter
+    let governance = legacy_governance.upgrade(
+        ctx.accounts.treasury.key(),
+        ctx.accounts.usdc_mint.key(),
+        ctx.accounts.reit_mint.key(),
+        migrated_usdc,
+        Clock::get()?.unix_timestamp,
+    );
+    grow_account(
+        &governance_info,
+        Governance::SPACE,
+        &ctx.accounts.fund_authority,
+        &ctx.accounts.system_program,
+    )?;
+    governance.try_serialize(&mut &mut governance_info.try_borrow_mut_data()?[..])?;
+    
+    let mut queue_data = queue_info.try_borrow_mut_data()?;
+    queue_data.fill(0);
+    redemption_queue.try_serialize(&mut &mut queue_data[..])?;
+    drop(queue_data);
+    
+    // Classic SPL mints have no transfer hook (see MigrateGovernance)
+    if *ctx.accounts.reit_mint.to_account_info().owner == anchor_spl::token::ID {
+        msg!("REI mint is a classic SPL Token mint: transfers bypass KYC and yield checkpoints");
+    }
+    
+    msg!(
+        "Governance migrated: treasury {} ({} USDC moved), next redemption id {}",
+        governance.treasury,
+        migrated_usdc,
+        redemption_queue.next_request_id
+    );
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn legacy_governance_bytes(admin: Pubkey, oracle: Pubkey, bump: u8) -> Vec<u8> {
+        let mut data = Governance::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(admin.as_ref());
+        data.extend_from_slice(oracle.as_ref());
+        data.extend_from_slice(&50u16.to_le_bytes());
+        data.extend_from_slice(&25u16.to_le_bytes());
+        data.extend_from_slice(&30u16.to_le_bytes());
+        data.push(1);
+        data.push(bump);
+        data
+    }
+    
+    fn legacy_queue_bytes(statuses: &[u8]) -> Vec<u8> {
+        let mut data = RedemptionQueue::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&(statuses.len() as u32).to_le_bytes());
+        for status in statuses {
+            data.extend_from_slice(Pubkey::new_unique().as_ref());
+            data.extend_from_slice(&10u64.to_le_bytes());
+            data.extend_from_slice(&5i64.to_le_bytes());
+            data.push(*status);
+        }
+        data.push(249);
+        data.resize(LegacyRedemptionQueue::SPACE, 0);
+        data
+    }
+    
+    fn unpack_queue(data: &[u8]) -> LegacyRedemptionQueue {
+        unpack_legacy(data, &RedemptionQueue::DISCRIMINATOR, LegacyRedemptionQueue::SPACE).unwrap()
+    }
+    
+    #[test]
+    fn migrates_baseline_governance_bytes() {
+        let admin = Pubkey::new_unique();
+        let oracle = Pubkey::new_unique();
+        let data = legacy_governance_bytes(admin, oracle, 253);
+        assert_eq!(data.len(), LegacyGovernance::SPACE);
+        
+        let legacy: LegacyGovernance =
+            unpack_legacy(&data, &Governance::DISCRIMINATOR, LegacyGovernance::SPACE).unwrap();
+        let treasury = Pubkey::new_unique();
+        let upgraded = legacy.upgrade(treasury, Pubkey::new_unique(), Pubkey::new_unique(), 7, 100);
+        
+        let mut migrated = vec![0u8; Governance::SPACE];
+        upgraded.try_serialize(&mut &mut migrated[..]).unwrap();
+        let governance = Governance::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(governance.admin_key, admin);
+        assert_eq!(governance.oracle_signer, oracle);
+        assert_eq!(
+            (governance.management_fee_bps, governance.mint_fee_bps, governance.redemption_fee_bps),
+            (50, 25, 30)
+        );
+        assert!(governance.paused);
+        assert_eq!(governance.bump, 253);
+        assert_eq!(governance.treasury, treasury);
+        assert_eq!(governance.total_deposits, 7);
+        assert_eq!(governance.last_fee_accrual, 100);
+    }
+    
+    #[test]
+    fn migrates_settled_baseline_redemption_queue() {
+        let queue = unpack_queue(&legacy_queue_bytes(&[LegacyRedemptionRequest::SETTLED; 2]))
+            .upgrade()
+            .unwrap();
+        assert_eq!(queue.next_request_id, 3);
+        assert_eq!(queue.open_requests, 0);
+        assert_eq!(queue.bump, 249);
+    }
+    
+    #[test]
+    fn refuses_to_drop_open_legacy_redemptions() {
+        // Pending and approved requests both block the migration
+        for open_status in [0, 1] {
+            let data = legacy_queue_bytes(&[LegacyRedemptionRequest::SETTLED, open_status]);
+            assert!(unpack_queue(&data).upgrade().is_err());
+        }
+    }
+    
+    #[test]
+    fn rejects_migrated_layouts() {
+        let data = vec![0u8; Governance::SPACE];
+        assert!(
+            unpack_legacy::<LegacyGovernance>(&data, &Governance::DISCRIMINATOR, LegacyGovernance::SPACE)
+                .is_err()
+        );
+    }
+}
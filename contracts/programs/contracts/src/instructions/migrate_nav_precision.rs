@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::RenewraError;
+use crate::instructions::migrate_governance::{grow_account, unpack_legacy};
+use crate::state::{Governance, NavHistory, NavOracle};
+
+/// Second step of upgrading a baseline deployment (after migrate_governance)
+#[derive(Accounts)]
+pub struct MigrateNavPrecision<'info> {
+    /// Fund administrator - must match governance.admin_key (pays rent)
+    #[account(
+        mut,
+        constraint = fund_authority.key() == governance.admin_key @ RenewraError::InvalidAuthority
+    )]
+    pub fund_authority: Signer<'info>,
+
+    /// Governance account, already in the current layout
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// CHECK: NAV oracle PDA in the baseline 33-byte layout (whole cents); checked,
+    /// grown and rescaled by the handler
+    #[account(
+        mut,
+        seeds = [NavOracle::SEED],
+        bump
+    )]
+    pub nav_oracle: UncheckedAccount<'info>,
+
+    /// NAV history, seeded with the migrated NAV
+    #[account(
+        init,
+        payer = fund_authority,
+        space = NavHistory::SPACE,
+        seeds = [NavHistory::SEED],
+        bump
+    )]
+    pub nav_history: AccountLoader<'info, NavHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Baseline NavOracle layout: 8 (discriminator) + 8 + 8 + 8 + 1 = 33 bytes, NAV in cents
+#[derive(AnchorDeserialize)]
+struct LegacyNavOracle {
+    latest_nav: u64,
+    previous_nav: u64,
+    timestamp: i64,
+    bump: u8,
+}
+
+impl LegacyNavOracle {
+    const SPACE: usize = 33;
+
+    /// Current-layout oracle with NAVs rescaled from whole cents to NAV_DECIMALS
+    fn upgrade(self) -> Result<NavOracle> {
+        let scale = NavOracle::LEGACY_CENTS_TO_NAV;
+        Ok(NavOracle {
+            latest_nav: self
+                .latest_nav
+                .checked_mul(scale)
+                .ok_or(RenewraError::ArithmeticOverflow)?,
+            previous_nav: self
+                .previous_nav
+                .checked_mul(scale)
+                .ok_or(RenewraError::ArithmeticOverflow)?,
+            timestamp: self.timestamp,
+            bump: self.bump,
+            cumulative_nav_seconds: 0,
+            cumulative_timestamp: self.timestamp,
+        })
+    }
+}
+
+pub fn handler(ctx: Context<MigrateNavPrecision>) -> Result<()> {
+    let nav_oracle_info = ctx.accounts.nav_oracle.to_account_info();
+    
+    // Step 1: Only oracles still in the 33-byte baseline layout migrate
+    require!(
+        nav_oracle_info.data_len() == LegacyNavOracle::SPACE,
+        RenewraError::NavAlreadyMigrated
+    );
+    let legacy_nav_oracle: LegacyNavOracle = unpack_legacy(
+        &nav_oracle_info.try_borrow_data()?,
+        &NavOracle::DISCRIMINATOR,
+        LegacyNavOracle::SPACE,
+    )?;
+    
+    // Step 2: Grow the oracle and write it back with NAVs in 6-decimal fixed point
+    let nav_oracle = legacy_nav_oracle.upgrade()?;
+    grow_account(
+        &nav_oracle_info,
+        NavOracle::SPACE,
+        &ctx.accounts.fund_authority,
+        &ctx.accounts.system_program,
+    )?;
+    nav_oracle.try_serialize(&mut &mut nav_oracle_info.try_borrow_mut_data()?[..])?;
+    
+    // Step 3: Seed NAV history with the migrated NAV (first TWAP checkpoint)
+    let mut nav_history = ctx.accounts.nav_history.load_init()?;
+    nav_history.bump = ctx.bumps.nav_history;
+    nav_history.push(
+        nav_oracle.latest_nav,
+        nav_oracle.timestamp,
+        ctx.accounts.fund_authority.key(),
+        nav_oracle.cumulative_nav_seconds,
+    );
+    drop(nav_history);
+    
+    msg!(
+        "NAV oracle migrated: NAV {} ({} decimals)",
+        nav_oracle.latest_nav,
+        NavOracle::NAV_DECIMALS
+    );
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn legacy_nav_oracle_bytes(latest: u64, previous: u64, timestamp: i64, bump: u8) -> Vec<u8> {
+        let mut data = NavOracle::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&latest.to_le_bytes());
+        data.extend_from_slice(&previous.to_le_bytes());
+        data.extend_from_slice(&timestamp.to_le_bytes());
+        data.push(bump);
+        data
+    }
+    
+    #[test]
+    fn migrates_baseline_nav_oracle_bytes() {
+        let data = legacy_nav_oracle_bytes(967, 950, 1_700_000_000, 251);
+        assert_eq!(data.len(), LegacyNavOracle::SPACE);
+        
+        let legacy: LegacyNavOracle =
+            unpack_legacy(&data, &NavOracle::DISCRIMINATOR, LegacyNavOracle::SPACE).unwrap();
+        let mut migrated = data.clone();
+        migrated.resize(NavOracle::SPACE, 0);
+        legacy.upgrade().unwrap().try_serialize(&mut &mut migrated[..]).unwrap();
+        
+        // Original fields keep their offsets; bump stays at byte 32
+        assert_eq!(migrated[32], 251);
+        let nav_oracle = NavOracle::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(nav_oracle.latest_nav, 9_670_000);
+        assert_eq!(nav_oracle.previous_nav, 9_500_000);
+        assert_eq!(nav_oracle.timestamp, 1_700_000_000);
+        assert_eq!(nav_oracle.bump, 251);
+    }
+    
+    #[test]
+    fn rejects_migrated_layouts() {
+        let data = vec![0u8; NavOracle::SPACE];
+        assert!(
+            unpack_legacy::<LegacyNavOracle>(&data, &NavOracle::DISCRIMINATOR, LegacyNavOracle::SPACE)
+                .is_err()
+        );
+    }
+}
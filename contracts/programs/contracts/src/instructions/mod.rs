@@ -4,6 +4,27 @@ pub mod submit_nav;
 pub mod subscribe;
 pub mod distribute_yield;
 pub mod request_redeem;
+pub mod approve_redeem;
+pub mod settle_redeem;
+pub mod cancel_redeem;
+pub mod set_paused;
+pub mod update_governance;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod rotate_oracle;
+pub mod configure_oracles;
+pub mod get_twap;
+pub mod migrate_governance;
+pub mod migrate_nav_precision;
+pub mod migrate_fund_accounts;
+pub mod claim_yield;
+pub mod accrue_management_fee;
+pub mod sweep_fees;
+pub mod issue_attestation;
+pub mod revoke_attestation;
+pub mod initialize_token_metadata;
+pub mod update_token_metadata_uri;
+pub mod checkpoint_transfer;
 
 pub use initialize_fund::*;
 pub use initialize_mint::*;
@@ -11,3 +32,24 @@ pub use submit_nav::*;
 pub use subscribe::*;
 pub use distribute_yield::*;
 pub use request_redeem::*;
+pub use approve_redeem::*;
+pub use settle_redeem::*;
+pub use cancel_redeem::*;
+pub use set_paused::*;
+pub use update_governance::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use rotate_oracle::*;
+pub use configure_oracles::*;
+pub use get_twap::*;
+pub use migrate_governance::*;
+pub use migrate_nav_precision::*;
+pub use migrate_fund_accounts::*;
+pub use claim_yield::*;
+pub use accrue_management_fee::*;
+pub use sweep_fees::*;
+pub use issue_attestation::*;
+pub use revoke_attestation::*;
+pub use initialize_token_metadata::*;
+pub use update_token_metadata_uri::*;
+pub use checkpoint_transfer::*;
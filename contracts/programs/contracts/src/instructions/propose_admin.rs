@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::events::AdminProposedEvent;
+use crate::state::Governance;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    /// Fund administrator - must match governance.admin_key
+    #[account(
+        constraint = fund_authority.key() == governance.admin_key @ RenewraError::InvalidAuthority
+    )]
+    pub fund_authority: Signer<'info>,
+
+    /// Governance account storing the pending admin
+    #[account(
+        mut,
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+pub fn handler(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+    let clock = Clock::get()?;
+    
+    // Record the proposal; Pubkey::default() clears any pending proposal
+    governance.pending_admin = new_admin;
+    
+    emit!(AdminProposedEvent {
+        current_admin: governance.admin_key,
+        proposed_admin: new_admin,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Admin transfer proposed: {} -> {}", governance.admin_key, new_admin);
+    
+    Ok(())
+}
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::RenewraError;
 use crate::events::RedeemRequestEvent;
-use crate::state::{Governance, RedemptionQueue, RedemptionRequest, RedemptionStatus};
+use crate::state::{
+    Governance, InvestorAttestation, RedemptionQueue, RedemptionRequest, RedemptionStatus,
+    UserYield, YieldState,
+};
 
 #[derive(Accounts)]
 pub struct RequestRedeem<'info> {
@@ -19,7 +23,7 @@ pub struct RequestRedeem<'info> {
     )]
     pub governance: Account<'info, Governance>,
 
-    /// Redemption queue PDA storing pending requests
+    /// Redemption queue PDA assigning request ids
     #[account(
         mut,
         seeds = [RedemptionQueue::SEED],
@@ -27,17 +31,84 @@ pub struct RequestRedeem<'info> {
     )]
     pub redemption_queue: Account<'info, RedemptionQueue>,
 
-    /// User's REI token account (to verify balance)
+    /// New redemption request PDA keyed by the next request id
+    #[account(
+        init,
+        payer = requester,
+        space = RedemptionRequest::SPACE,
+        seeds = [
+            RedemptionRequest::SEED,
+            redemption_queue.next_request_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// User's associated REI token account (source of escrowed tokens)
+    #[account(
+        mut,
+        constraint = user_reit_account.owner == requester.key() @ RenewraError::InvalidAuthority,
+        constraint = user_reit_account.mint == governance.reit_mint @ RenewraError::InvalidReitMint,
+        constraint = governance.is_reit_ata(&user_reit_account) @ RenewraError::InvalidReitAccount
+    )]
+    pub user_reit_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Per-user escrow PDA token account (holds REI until settlement or cancellation)
+    #[account(
+        init_if_needed,
+        payer = requester,
+        seeds = [RedemptionQueue::ESCROW_SEED, requester.key().as_ref()],
+        bump,
+        token::mint = reit_mint,
+        token::authority = governance,
+        token::token_program = token_program,
+    )]
+    pub redeem_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// REI token mint
+    #[account(
+        seeds = [b"reit_mint"],
+        bump,
+        address = governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    /// Fund-wide reward index and earning supply
     #[account(
         mut,
-        constraint = user_reit_account.owner == requester.key() @ RenewraError::InvalidAuthority
+        seeds = [YieldState::SEED],
+        bump = yield_state.bump
+    )]
+    pub yield_state: Account<'info, YieldState>,
+
+    /// Holder's yield checkpoint (created on first use at the current index)
+    #[account(
+        init_if_needed,
+        payer = requester,
+        space = UserYield::SPACE,
+        seeds = [UserYield::SEED, requester.key().as_ref()],
+        bump
+    )]
+    pub user_yield: Box<Account<'info, UserYield>>,
+
+    /// Investor attestation (required only while governance.require_kyc is set)
+    #[account(
+        seeds = [InvestorAttestation::SEED, requester.key().as_ref()],
+        bump = investor_attestation.bump
     )]
-    pub user_reit_account: Account<'info, TokenAccount>,
+    pub investor_attestation: Option<Account<'info, InvestorAttestation>>,
 
-    pub token_program: Program<'info, Token>,
+    /// Token-2022 program (REI mint)
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<RequestRedeem>, token_amount: u64) -> Result<()> {
+/// Remaining accounts: the REI transfer hook's extra accounts (hook program,
+/// ExtraAccountMetaList and its resolved metas), forwarded to the transfer.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RequestRedeem<'info>>,
+    token_amount: u64,
+) -> Result<()> {
     // Validate token amount
     require!(token_amount > 0, RenewraError::InvalidAmount);
     
@@ -45,33 +116,58 @@ pub fn handler(ctx: Context<RequestRedeem>, token_amount: u64) -> Result<()> {
     let redemption_queue = &mut ctx.accounts.redemption_queue;
     let clock = Clock::get()?;
     
+    // Only attested investors may redeem while KYC is required
+    ctx.accounts.governance.check_kyc(
+        ctx.accounts.investor_attestation.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    
     // Step 1: Verify requester has sufficient REI tokens
     require!(
         user_reit_account.amount >= token_amount,
         RenewraError::InsufficientTokens
     );
     
-    // Validate queue has space
-    require!(
-        redemption_queue.requests.len() < RedemptionQueue::MAX_REQUESTS,
-        RenewraError::RedemptionQueueFull
-    );
+    // Step 2: Checkpoint accrued yield; escrowed tokens stop earning
+    let yield_state = &mut ctx.accounts.yield_state;
+    let balance_before = user_reit_account.amount;
+    let user_yield = &mut ctx.accounts.user_yield;
+    user_yield.open_if_new(ctx.accounts.requester.key(), yield_state.reward_per_token, ctx.bumps.user_yield);
+    user_yield.checkpoint_transfer(yield_state, balance_before, balance_before - token_amount)?;
     
-    // Step 2: Create RedemptionRequest struct
-    let request = RedemptionRequest {
-        requester: ctx.accounts.requester.key(),
+    // Step 3: Move tokens into escrow so the queued amount stays backed
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.user_reit_account.to_account_info(),
+        ctx.accounts.reit_mint.to_account_info(),
+        ctx.accounts.redeem_escrow.to_account_info(),
+        ctx.accounts.requester.to_account_info(),
+        ctx.remaining_accounts,
         token_amount,
-        requested_at: clock.unix_timestamp,
-        status: RedemptionStatus::Pending,
-    };
+        ctx.accounts.reit_mint.decimals,
+        &[],
+    )?;
     
-    // Calculate request_id before pushing (1-indexed)
-    let request_id = (redemption_queue.requests.len() + 1) as u64;
+    // Step 4: Assign the next request id (stable for the lifetime of the request)
+    let request_id = redemption_queue.next_request_id;
+    redemption_queue.next_request_id = request_id
+        .checked_add(1)
+        .ok_or(RenewraError::ArithmeticOverflow)?;
+    redemption_queue.open_requests = redemption_queue
+        .open_requests
+        .checked_add(1)
+        .ok_or(RenewraError::ArithmeticOverflow)?;
     
-    // Step 3: Push to redemption_queue.requests vector
-    redemption_queue.requests.push(request);
+    // Step 5: Populate the RedemptionRequest PDA
+    let request = &mut ctx.accounts.redemption_request;
+    request.request_id = request_id;
+    request.requester = ctx.accounts.requester.key();
+    request.token_amount = token_amount;
+    request.requested_at = clock.unix_timestamp;
+    request.status = RedemptionStatus::Pending;
+    request.bump = ctx.bumps.redemption_request;
     
-    // Step 4: Emit RedeemRequestEvent
+    // Step 6: Emit RedeemRequestEvent
     emit!(RedeemRequestEvent {
         requester: ctx.accounts.requester.key(),
         token_amount,
@@ -80,7 +176,7 @@ pub fn handler(ctx: Context<RequestRedeem>, token_amount: u64) -> Result<()> {
     });
     
     msg!(
-        "Redemption request #{}: {} tokens queued for settlement",
+        "Redemption request #{}: {} tokens escrowed and queued for settlement",
         request_id,
         token_amount
     );
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::events::AttestationRevokedEvent;
+use crate::state::{Governance, InvestorAttestation};
+
+#[derive(Accounts)]
+pub struct RevokeAttestation<'info> {
+    /// Compliance authority - must match governance.compliance_authority
+    #[account(
+        constraint = compliance_authority.key() == governance.compliance_authority @ RenewraError::InvalidAuthority
+    )]
+    pub compliance_authority: Signer<'info>,
+
+    /// Governance account holding the compliance authority key
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Investor attestation PDA to revoke (kept on-chain for the audit trail)
+    #[account(
+        mut,
+        seeds = [InvestorAttestation::SEED, investor_attestation.investor.as_ref()],
+        bump = investor_attestation.bump
+    )]
+    pub investor_attestation: Account<'info, InvestorAttestation>,
+}
+
+pub fn handler(ctx: Context<RevokeAttestation>) -> Result<()> {
+    let attestation = &mut ctx.accounts.investor_attestation;
+    let clock = Clock::get()?;
+    
+    attestation.revoked = true;
+    
+    emit!(AttestationRevokedEvent {
+        investor: attestation.investor,
+        revoked_by: ctx.accounts.compliance_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Attestation revoked for {}", attestation.investor);
+    
+    Ok(())
+}
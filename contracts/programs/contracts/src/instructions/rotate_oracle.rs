@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::events::OracleRotationScheduledEvent;
+use crate::state::Governance;
+
+#[derive(Accounts)]
+pub struct RotateOracle<'info> {
+    /// Fund administrator - must match governance.admin_key
+    #[account(
+        constraint = fund_authority.key() == governance.admin_key @ RenewraError::InvalidAuthority
+    )]
+    pub fund_authority: Signer<'info>,
+
+    /// Governance account storing oracle keys
+    #[account(
+        mut,
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+pub fn handler(
+    ctx: Context<RotateOracle>,
+    next_oracle_signer: Pubkey,
+    activation_slot: u64,
+    overlap_slots: u64,
+) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+    let clock = Clock::get()?;
+    
+    // Step 1: Complete a finished rotation, then schedule the new one
+    // (rejected while a previous rotation is still in its overlap window)
+    let activation_slot = governance.schedule_oracle_rotation(
+        next_oracle_signer,
+        activation_slot,
+        overlap_slots,
+        clock.slot,
+    )?;
+    
+    // Step 2: Emit OracleRotationScheduledEvent
+    let cutoff_slot = activation_slot.saturating_add(overlap_slots);
+    emit!(OracleRotationScheduledEvent {
+        current_oracle_signer: governance.oracle_signer,
+        next_oracle_signer,
+        activation_slot,
+        cutoff_slot,
+        scheduled_by: ctx.accounts.fund_authority.key(),
+    });
+    
+    msg!(
+        "Oracle rotation scheduled: {} -> {} (active at slot {}, old key cut off at slot {})",
+        governance.oracle_signer,
+        next_oracle_signer,
+        activation_slot,
+        cutoff_slot
+    );
+    
+    Ok(())
+}
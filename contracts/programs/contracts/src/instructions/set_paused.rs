@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::events::PauseEvent;
+use crate::state::Governance;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// Admin or guardian - must match governance.admin_key or governance.guardian
+    #[account(
+        constraint = authority.key() == governance.admin_key
+            || authority.key() == governance.guardian @ RenewraError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    /// Governance account holding the pause flag
+    #[account(
+        mut,
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+pub fn handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+    
+    // Guardian is a kill-switch only: unpausing requires the admin key
+    if !paused {
+        require!(
+            authority == governance.admin_key,
+            RenewraError::GuardianCannotUnpause
+        );
+    }
+    
+    governance.paused = paused;
+    
+    emit!(PauseEvent {
+        paused,
+        triggered_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Fund {} by {}", if paused { "paused" } else { "unpaused" }, authority);
+    
+    Ok(())
+}
@@ -0,0 +1,265 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::RenewraError;
+use crate::instructions::accrue_management_fee;
+use crate::events::RedeemSettledEvent;
+use crate::state::{
+    FeeVault, Governance, InvestorAttestation, NavHistory, NavOracle, RedemptionQueue,
+    RedemptionRequest, RedemptionStatus, YieldState,
+};
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct SettleRedeem<'info> {
+    /// Token holder settling an approved redemption
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    /// Governance account for fee params and pause check
+    #[account(
+        mut,
+        seeds = [Governance::SEED],
+        bump = governance.bump,
+        constraint = !governance.paused @ RenewraError::FundPaused
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// NAV oracle for current price
+    #[account(
+        seeds = [NavOracle::SEED],
+        bump = nav_oracle.bump,
+        constraint = nav_oracle.latest_nav > 0 @ RenewraError::InvalidNavPrice
+    )]
+    pub nav_oracle: Account<'info, NavOracle>,
+
+    /// NAV history checkpoints for TWAP pricing
+    #[account(
+        seeds = [NavHistory::SEED],
+        bump = nav_history.load()?.bump
+    )]
+    pub nav_history: AccountLoader<'info, NavHistory>,
+
+    /// Redemption queue PDA tracking open requests
+    #[account(
+        mut,
+        seeds = [RedemptionQueue::SEED],
+        bump = redemption_queue.bump
+    )]
+    pub redemption_queue: Account<'info, RedemptionQueue>,
+
+    /// Redemption request PDA to settle (closed afterwards, rent returned to requester)
+    #[account(
+        mut,
+        seeds = [RedemptionRequest::SEED, request_id.to_le_bytes().as_ref()],
+        bump = redemption_request.bump,
+        close = requester
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// Per-user escrow PDA token account (escrowed tokens are burned from here)
+    #[account(
+        mut,
+        seeds = [RedemptionQueue::ESCROW_SEED, requester.key().as_ref()],
+        bump
+    )]
+    pub redeem_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// User's USDC token account (receives payout)
+    #[account(
+        mut,
+        constraint = user_usdc_account.owner == requester.key() @ RenewraError::InvalidAuthority,
+        constraint = user_usdc_account.mint == governance.usdc_mint @ RenewraError::InvalidUsdcMint
+    )]
+    pub user_usdc_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury PDA token account (pays out USDC, authority = governance PDA)
+    #[account(
+        mut,
+        address = governance.treasury @ RenewraError::InvalidTreasury
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// USDC mint (decimals for transfer_checked)
+    #[account(
+        address = governance.usdc_mint @ RenewraError::InvalidUsdcMint
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// REI token mint (supply decreases on burn)
+    #[account(
+        mut,
+        seeds = [b"reit_mint"],
+        bump,
+        address = governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    /// Fee recipient's REI token account (receives any management fee accrued first)
+    #[account(
+        mut,
+        constraint = fee_recipient_account.owner == governance.fee_recipient @ RenewraError::InvalidFeeRecipient,
+        constraint = fee_recipient_account.mint == governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub fee_recipient_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Fee vault earmarking collected fees into buckets
+    #[account(
+        mut,
+        seeds = [FeeVault::SEED],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    /// Fund-wide yield totals (unclaimed yield is not available for payouts)
+    #[account(
+        seeds = [YieldState::SEED],
+        bump = yield_state.bump
+    )]
+    pub yield_state: Box<Account<'info, YieldState>>,
+
+    /// Investor attestation (required only while governance.require_kyc is set)
+    #[account(
+        seeds = [InvestorAttestation::SEED, requester.key().as_ref()],
+        bump = investor_attestation.bump
+    )]
+    pub investor_attestation: Option<Account<'info, InvestorAttestation>>,
+
+    /// Token-2022 program (REI mint)
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the USDC mint
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<SettleRedeem>, request_id: u64, min_usdc_out: u64) -> Result<()> {
+    // Charge any management fee due before pricing
+    accrue_management_fee::accrue(
+        &mut ctx.accounts.governance,
+        &ctx.accounts.reit_mint,
+        &ctx.accounts.fee_recipient_account,
+        &ctx.accounts.token_program,
+    )?;
+    
+    let governance = &ctx.accounts.governance;
+    let nav_oracle = &ctx.accounts.nav_oracle;
+    let redemption_queue = &mut ctx.accounts.redemption_queue;
+    let request = &mut ctx.accounts.redemption_request;
+    let clock = Clock::get()?;
+    
+    // Step 1: Only the original requester can settle, and only once approved
+    require!(
+        request.requester == ctx.accounts.requester.key(),
+        RenewraError::InvalidAuthority
+    );
+    require!(
+        request.status == RedemptionStatus::Approved,
+        RenewraError::InvalidRedemptionStatus
+    );
+    
+    let token_amount = request.token_amount;
+    require!(
+        ctx.accounts.redeem_escrow.amount >= token_amount,
+        RenewraError::InsufficientTokens
+    );
+    
+    // Only attested investors may be paid out while KYC is required; a revoked
+    // investor can still cancel_redeem to get the escrowed REI back
+    governance.check_kyc(
+        ctx.accounts.investor_attestation.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    
+    // Refuse to price off a NAV the oracle has stopped updating
+    require!(
+        !nav_oracle.is_stale(clock.unix_timestamp, governance.max_nav_age_secs),
+        RenewraError::StaleNav
+    );
+    
+    // Step 2: Read pricing NAV (6 decimals, e.g., 10_000_000 = $10.00): spot or TWAP per governance
+    let nav = nav_oracle.pricing_nav(
+        governance,
+        &*ctx.accounts.nav_history.load()?,
+        clock.unix_timestamp,
+    )?;
+    
+    // Step 3: Calculate gross USDC, rounded down: usdc = token_amount * nav / 10^6
+    let gross_usdc = NavOracle::tokens_to_usdc(token_amount, nav)?;
+    
+    // Step 4: Calculate redemption fee: fee = ceil(gross_usdc * redemption_fee_bps / 10000)
+    let fee_amount = Governance::fee_for(gross_usdc, governance.redemption_fee_bps)?;
+    
+    // Step 5: Calculate net USDC after fee (fee stays in treasury, earmarked in the fee vault)
+    let net_usdc = gross_usdc
+        .checked_sub(fee_amount)
+        .ok_or(RenewraError::ArithmeticOverflow)?;
+    
+    require!(net_usdc > 0, RenewraError::InvalidAmount);
+    
+    // Protect the requester against NAV moving between quote and execution
+    require!(net_usdc >= min_usdc_out, RenewraError::SlippageExceeded);
+    
+    // Step 6: Mark request as settled and earmark the fee before moving funds;
+    // the payout may not spend earmarked fees (including this one) or owed yield
+    request.status = RedemptionStatus::Settled;
+    ctx.accounts.fee_vault.record(fee_amount, governance)?;
+    require!(
+        ctx.accounts
+            .fee_vault
+            .available_liquidity(ctx.accounts.treasury.amount, &ctx.accounts.yield_state)?
+            >= net_usdc,
+        RenewraError::InsufficientTreasuryFunds
+    );
+    ctx.accounts.governance.total_deposits =
+        ctx.accounts.governance.total_deposits.saturating_sub(gross_usdc);
+    redemption_queue.open_requests = redemption_queue.open_requests.saturating_sub(1);
+    
+    // Step 7: Burn escrowed REI tokens using governance PDA as signer
+    let seeds = &[Governance::SEED, &[ctx.accounts.governance.bump]];
+    let signer_seeds = &[&seeds[..]];
+    
+    let burn_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.reit_mint.to_account_info(),
+            from: ctx.accounts.redeem_escrow.to_account_info(),
+            authority: ctx.accounts.governance.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::burn(burn_ctx, token_amount)?;
+    
+    // Step 8: Transfer net USDC from treasury to user using governance PDA as signer
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.usdc_token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.treasury.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+            to: ctx.accounts.user_usdc_account.to_account_info(),
+            authority: ctx.accounts.governance.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, net_usdc, ctx.accounts.usdc_mint.decimals)?;
+    
+    // Step 9: Emit RedeemSettledEvent
+    emit!(RedeemSettledEvent {
+        requester: ctx.accounts.requester.key(),
+        token_amount,
+        usdc_amount: net_usdc,
+        fee_amount,
+        nav_at_settlement: nav,
+        settled_at: clock.unix_timestamp,
+        request_id,
+    });
+    
+    msg!(
+        "Redemption request #{} settled: {} tokens -> {} USDC (fee: {}) at NAV {}",
+        request_id,
+        token_amount,
+        net_usdc,
+        fee_amount,
+        nav
+    );
+    
+    Ok(())
+}
@@ -1,24 +1,30 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::RenewraError;
-use crate::events::NavUpdateEvent;
-use crate::state::{Governance, NavOracle};
+use crate::events::{NavAnomalyEvent, NavRoundClosedEvent, NavUpdateEvent, PauseEvent};
+use crate::state::{Governance, NavHistory, NavOracle, NavSubmission, OracleConfig};
 
 #[derive(Accounts)]
 pub struct SubmitNav<'info> {
-    /// Oracle signer - must match governance.oracle_signer
-    #[account(
-        constraint = oracle_signer.key() == governance.oracle_signer @ RenewraError::OracleMismatch
-    )]
+    /// Oracle signer - must be in the oracle set, or governance.oracle_signer when the set is empty
     pub oracle_signer: Signer<'info>,
 
-    /// Governance account to verify oracle signer
+    /// Governance account to verify oracle signer (mut to complete key rotations)
     #[account(
+        mut,
         seeds = [Governance::SEED],
         bump = governance.bump
     )]
     pub governance: Account<'info, Governance>,
 
+    /// Oracle set and pending NAV round
+    #[account(
+        mut,
+        seeds = [OracleConfig::SEED],
+        bump = oracle_config.bump
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
     /// NAV oracle PDA to update
     #[account(
         mut,
@@ -26,33 +32,171 @@ pub struct SubmitNav<'info> {
         bump = nav_oracle.bump
     )]
     pub nav_oracle: Account<'info, NavOracle>,
+
+    /// NAV history ring appended on every finalized update
+    #[account(
+        mut,
+        seeds = [NavHistory::SEED],
+        bump = nav_history.load()?.bump
+    )]
+    pub nav_history: AccountLoader<'info, NavHistory>,
 }
 
 pub fn handler(ctx: Context<SubmitNav>, new_nav: u64) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+    let oracle_config = &mut ctx.accounts.oracle_config;
     let nav_oracle = &mut ctx.accounts.nav_oracle;
+    let oracle_signer = ctx.accounts.oracle_signer.key();
     let clock = Clock::get()?;
     
+    // Verify signer: oracle set member, or (single-signer mode) the current / next
+    // governance oracle key during a rotation
+    if oracle_config.has_oracle_set() {
+        require!(
+            oracle_config.is_member(&oracle_signer),
+            RenewraError::OracleMismatch
+        );
+    } else {
+        require!(
+            governance.is_oracle_signer(&oracle_signer, clock.slot),
+            RenewraError::OracleMismatch
+        );
+        
+        // Retire the old oracle key once its overlap window has passed
+        if governance.complete_oracle_rotation(clock.slot) {
+            msg!("Oracle rotation completed: {}", governance.oracle_signer);
+        }
+    }
+    
     // Validate NAV is reasonable (non-zero)
     require!(new_nav > 0, RenewraError::InvalidNavPrice);
     
-    // Step 1: Store previous NAV
-    nav_oracle.previous_nav = nav_oracle.latest_nav;
+    // Step 1: Discard a pending round that timed out before reaching quorum
+    if oracle_config.submission_count > 0
+        && clock.unix_timestamp.saturating_sub(oracle_config.round_opened_at)
+            > oracle_config.round_timeout_secs
+    {
+        emit!(NavRoundClosedEvent {
+            round_id: oracle_config.round_id,
+            final_nav: 0,
+            finalized: false,
+            participants: oracle_config.pending().iter().map(|s| s.oracle).collect(),
+            timestamp: clock.unix_timestamp,
+        });
+        msg!("NAV round #{} expired without quorum", oracle_config.round_id);
+        oracle_config.reset_round();
+    }
+    
+    // Step 2: Record this oracle's submission in the pending round
+    require!(
+        !oracle_config.has_submitted(&oracle_signer),
+        RenewraError::DuplicateNavSubmission
+    );
+    if oracle_config.submission_count == 0 {
+        oracle_config.round_opened_at = clock.unix_timestamp;
+    }
+    let index = oracle_config.submission_count as usize;
+    oracle_config.submissions[index] = NavSubmission {
+        oracle: oracle_signer,
+        nav: new_nav,
+    };
+    oracle_config.submission_count += 1;
+    
+    // Step 3: Finalize once M submissions agree; otherwise wait for more oracles
+    let participants: Vec<Pubkey> = oracle_config.pending().iter().map(|s| s.oracle).collect();
+    let round_id = oracle_config.round_id;
+    let agreed_nav = match oracle_config.agreed_nav() {
+        Some(nav) => nav,
+        None => {
+            if participants.len() >= oracle_config.max_participants() {
+                // Every oracle has submitted and they still disagree: close the round
+                emit!(NavRoundClosedEvent {
+                    round_id,
+                    final_nav: 0,
+                    finalized: false,
+                    participants,
+                    timestamp: clock.unix_timestamp,
+                });
+                msg!("NAV round #{} closed without agreement", round_id);
+                oracle_config.reset_round();
+            } else {
+                msg!(
+                    "NAV round #{}: {} from {} ({}/{} submissions)",
+                    round_id,
+                    new_nav,
+                    oracle_signer,
+                    participants.len(),
+                    oracle_config.effective_quorum()
+                );
+            }
+            return Ok(());
+        }
+    };
+    
+    oracle_config.reset_round();
+    emit!(NavRoundClosedEvent {
+        round_id,
+        final_nav: agreed_nav,
+        finalized: true,
+        participants,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    // Step 4: Circuit breaker - NAV may only move within the governance deviation band
+    let deviation_bps = nav_oracle.deviation_bps(agreed_nav);
+    if deviation_bps > governance.max_nav_deviation_bps as u64 {
+        require!(
+            governance.nav_deviation_auto_pause,
+            RenewraError::NavDeviationTooLarge
+        );
+        
+        // Auto-pause mode: keep the current NAV, stop the fund and flag the anomaly
+        governance.paused = true;
+        
+        emit!(NavAnomalyEvent {
+            current_nav: nav_oracle.latest_nav,
+            rejected_nav: agreed_nav,
+            deviation_bps,
+            max_deviation_bps: governance.max_nav_deviation_bps,
+            timestamp: clock.unix_timestamp,
+            oracle_signer,
+        });
+        emit!(PauseEvent {
+            paused: true,
+            triggered_by: oracle_signer,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        msg!(
+            "NAV anomaly: {} -> {} ({} bps > {} bps), fund paused",
+            nav_oracle.latest_nav,
+            agreed_nav,
+            deviation_bps,
+            governance.max_nav_deviation_bps
+        );
+        return Ok(());
+    }
     
-    // Step 2: Update latest NAV
-    nav_oracle.latest_nav = new_nav;
+    // Step 5: Roll the TWAP accumulator forward and store previous / latest NAV
+    nav_oracle.record(agreed_nav, clock.unix_timestamp);
     
-    // Step 3: Update timestamp
-    nav_oracle.timestamp = clock.unix_timestamp;
+    // Step 6: Append to the on-chain NAV history (also the TWAP checkpoint)
+    ctx.accounts.nav_history.load_mut()?.push(
+        agreed_nav,
+        clock.unix_timestamp,
+        oracle_signer,
+        nav_oracle.cumulative_nav_seconds,
+    );
     
-    // Step 4: Emit NavUpdateEvent
+    // Step 7: Emit NavUpdateEvent
     emit!(NavUpdateEvent {
         old_nav: nav_oracle.previous_nav,
         new_nav: nav_oracle.latest_nav,
         timestamp: clock.unix_timestamp,
-        oracle_signer: ctx.accounts.oracle_signer.key(),
+        oracle_signer,
     });
     
-    msg!("NAV updated: {} -> {} cents", nav_oracle.previous_nav, new_nav);
+    msg!("NAV updated: {} -> {}", nav_oracle.previous_nav, agreed_nav);
     
     Ok(())
 }
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked};
 
 use crate::errors::RenewraError;
+use crate::instructions::accrue_management_fee;
 use crate::events::SubscribeEvent;
-use crate::state::{Governance, NavOracle};
+use crate::state::{
+    FeeVault, Governance, InvestorAttestation, InvestorPosition, NavHistory, NavOracle, UserYield,
+    YieldState,
+};
 
 #[derive(Accounts)]
 pub struct Subscribe<'info> {
@@ -13,6 +17,7 @@ pub struct Subscribe<'info> {
 
     /// Governance account for fee params and pause check
     #[account(
+        mut,
         seeds = [Governance::SEED],
         bump = governance.bump,
         constraint = !governance.paused @ RenewraError::FundPaused
@@ -27,81 +32,204 @@ pub struct Subscribe<'info> {
     )]
     pub nav_oracle: Account<'info, NavOracle>,
 
+    /// NAV history checkpoints for TWAP pricing
+    #[account(
+        seeds = [NavHistory::SEED],
+        bump = nav_history.load()?.bump
+    )]
+    pub nav_history: AccountLoader<'info, NavHistory>,
+
     /// User's USDC token account (source of funds)
     #[account(
         mut,
-        constraint = user_usdc_account.owner == user.key() @ RenewraError::InvalidAuthority
+        constraint = user_usdc_account.owner == user.key() @ RenewraError::InvalidAuthority,
+        constraint = user_usdc_account.mint == governance.usdc_mint @ RenewraError::InvalidUsdcMint
+    )]
+    pub user_usdc_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// USDC mint (decimals for transfer_checked)
+    #[account(
+        address = governance.usdc_mint @ RenewraError::InvalidUsdcMint
     )]
-    pub user_usdc_account: Account<'info, TokenAccount>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// Treasury PDA token account (receives USDC)
-    #[account(mut)]
-    pub treasury: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = governance.treasury @ RenewraError::InvalidTreasury
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
 
-    /// User's REI token account (receives minted tokens)
+    /// User's associated REI token account (receives minted tokens)
     #[account(
         mut,
-        constraint = user_reit_account.owner == user.key() @ RenewraError::InvalidAuthority
+        constraint = user_reit_account.owner == user.key() @ RenewraError::InvalidAuthority,
+        constraint = user_reit_account.mint == governance.reit_mint @ RenewraError::InvalidReitMint,
+        constraint = governance.is_reit_ata(&user_reit_account) @ RenewraError::InvalidReitAccount
     )]
-    pub user_reit_account: Account<'info, TokenAccount>,
+    pub user_reit_account: InterfaceAccount<'info, TokenAccount>,
 
     /// REI token mint (mint authority = governance PDA)
-    #[account(mut)]
-    pub reit_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        address = governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    /// Fee recipient's REI token account (receives any management fee accrued first)
+    #[account(
+        mut,
+        constraint = fee_recipient_account.owner == governance.fee_recipient @ RenewraError::InvalidFeeRecipient,
+        constraint = fee_recipient_account.mint == governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub fee_recipient_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Fee vault earmarking collected fees into buckets
+    #[account(
+        mut,
+        seeds = [FeeVault::SEED],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
 
-    pub token_program: Program<'info, Token>,
+    /// Fund-wide reward index and earning supply
+    #[account(
+        mut,
+        seeds = [YieldState::SEED],
+        bump = yield_state.bump
+    )]
+    pub yield_state: Account<'info, YieldState>,
+
+    /// Holder's yield checkpoint (created on first use at the current index)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserYield::SPACE,
+        seeds = [UserYield::SEED, user.key().as_ref()],
+        bump
+    )]
+    pub user_yield: Box<Account<'info, UserYield>>,
+
+    /// Investor's lifetime subscription record (created on first subscription)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = InvestorPosition::SPACE,
+        seeds = [InvestorPosition::SEED, user.key().as_ref()],
+        bump
+    )]
+    pub investor_position: Box<Account<'info, InvestorPosition>>,
+
+    /// Investor attestation (required only while governance.require_kyc is set)
+    #[account(
+        seeds = [InvestorAttestation::SEED, user.key().as_ref()],
+        bump = investor_attestation.bump
+    )]
+    pub investor_attestation: Option<Account<'info, InvestorAttestation>>,
+
+    /// Token-2022 program (REI mint)
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the USDC mint
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Subscribe>, usdc_amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<Subscribe>, usdc_amount: u64, min_tokens_out: u64) -> Result<()> {
     // Validate amount
     require!(usdc_amount > 0, RenewraError::InvalidAmount);
     
+    // Charge any management fee due before pricing
+    accrue_management_fee::accrue(
+        &mut ctx.accounts.governance,
+        &ctx.accounts.reit_mint,
+        &ctx.accounts.fee_recipient_account,
+        &ctx.accounts.token_program,
+    )?;
+    
+    let clock = Clock::get()?;
+    
+    // Only attested investors may subscribe while KYC is required
+    ctx.accounts.governance.check_kyc(
+        ctx.accounts.investor_attestation.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    
+    // Enforce subscription limits and count the deposit toward the caps
+    let investor_position = &mut ctx.accounts.investor_position;
+    if investor_position.owner == Pubkey::default() {
+        investor_position.owner = ctx.accounts.user.key();
+        investor_position.bump = ctx.bumps.investor_position;
+    }
+    investor_position.last_subscribed_at = clock.unix_timestamp;
+    ctx.accounts
+        .governance
+        .record_subscription(investor_position, usdc_amount)?;
+    
     let governance = &ctx.accounts.governance;
     let nav_oracle = &ctx.accounts.nav_oracle;
     
-    // Step 1: Read current NAV (in cents, e.g., 1000 = $10.00)
-    let nav_cents = nav_oracle.latest_nav;
+    // Refuse to price off a NAV the oracle has stopped updating
+    require!(
+        !nav_oracle.is_stale(clock.unix_timestamp, governance.max_nav_age_secs),
+        RenewraError::StaleNav
+    );
+    
+    // Step 1: Read pricing NAV (6 decimals, e.g., 10_000_000 = $10.00): spot or TWAP per governance
+    let nav = nav_oracle.pricing_nav(
+        governance,
+        &*ctx.accounts.nav_history.load()?,
+        clock.unix_timestamp,
+    )?;
     
-    // Step 2: Calculate mint fee: fee = usdc_amount * mint_fee_bps / 10000
-    let fee_amount = (usdc_amount as u128)
-        .checked_mul(governance.mint_fee_bps as u128)
-        .ok_or(RenewraError::ArithmeticOverflow)?
-        .checked_div(10000)
-        .ok_or(RenewraError::ArithmeticOverflow)? as u64;
+    // Step 2: Calculate mint fee: fee = ceil(usdc_amount * mint_fee_bps / 10000)
+    let fee_amount = Governance::fee_for(usdc_amount, governance.mint_fee_bps)?;
     
     // Step 3: Calculate net USDC after fee
     let net_usdc = usdc_amount
         .checked_sub(fee_amount)
         .ok_or(RenewraError::ArithmeticOverflow)?;
     
-    // Step 4: Calculate tokens to mint using u128 for precision
-    // Formula: tokens = (net_usdc * 10^6) / nav_cents * 100
-    // net_usdc is in USDC smallest units (6 decimals), nav is in cents
-    // Result is in token smallest units (6 decimals)
+    // Step 4: Calculate tokens to mint, rounded down
+    // Formula: tokens = net_usdc * 10^6 / nav
+    // net_usdc, nav and tokens all carry 6 decimals
     // 
-    // Example: 100 USDC (100_000_000 smallest), NAV = 1000 cents ($10)
-    // tokens = (100_000_000 * 100) / 1000 = 10_000_000 (10 tokens with 6 decimals)
-    let tokens_to_mint = (net_usdc as u128)
-        .checked_mul(100) // Convert to cents precision
-        .ok_or(RenewraError::ArithmeticOverflow)?
-        .checked_div(nav_cents as u128)
-        .ok_or(RenewraError::ArithmeticOverflow)? as u64;
+    // Example: 100 USDC (100_000_000 smallest), NAV = 10_000_000 ($10)
+    // tokens = 100_000_000 * 1_000_000 / 10_000_000 = 10_000_000 (10 tokens with 6 decimals)
+    let tokens_to_mint = NavOracle::usdc_to_tokens(net_usdc, nav)?;
     
     require!(tokens_to_mint > 0, RenewraError::InvalidAmount);
     
-    // Step 5: Transfer full usdc_amount from user to treasury
-    // (fee stays in treasury as part of fund balance)
+    // Protect the user against NAV moving between quote and execution
+    require!(tokens_to_mint >= min_tokens_out, RenewraError::SlippageExceeded);
+    
+    // Step 5: Checkpoint accrued yield so the new tokens only earn future distributions
+    let yield_state = &mut ctx.accounts.yield_state;
+    let balance_before = ctx.accounts.user_reit_account.amount;
+    let user_yield = &mut ctx.accounts.user_yield;
+    user_yield.open_if_new(ctx.accounts.user.key(), yield_state.reward_per_token, ctx.bumps.user_yield);
+    user_yield.checkpoint_transfer(
+        yield_state,
+        balance_before,
+        balance_before
+            .checked_add(tokens_to_mint)
+            .ok_or(RenewraError::ArithmeticOverflow)?,
+    )?;
+    
+    // Step 6: Transfer full usdc_amount from user to treasury
+    // (fee stays in treasury, earmarked in the fee vault until swept)
+    ctx.accounts.fee_vault.record(fee_amount, &ctx.accounts.governance)?;
     let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        ctx.accounts.usdc_token_program.to_account_info(),
+        TransferChecked {
             from: ctx.accounts.user_usdc_account.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
             to: ctx.accounts.treasury.to_account_info(),
             authority: ctx.accounts.user.to_account_info(),
         },
     );
-    token::transfer(transfer_ctx, usdc_amount)?;
+    token_interface::transfer_checked(transfer_ctx, usdc_amount, ctx.accounts.usdc_mint.decimals)?;
     
-    // Step 6: Mint REI tokens to user using governance PDA as signer
+    // Step 7: Mint REI tokens to user using governance PDA as signer
     let seeds = &[Governance::SEED, &[governance.bump]];
     let signer_seeds = &[&seeds[..]];
     
@@ -114,22 +242,22 @@ pub fn handler(ctx: Context<Subscribe>, usdc_amount: u64) -> Result<()> {
         },
         signer_seeds,
     );
-    token::mint_to(mint_ctx, tokens_to_mint)?;
+    token_interface::mint_to(mint_ctx, tokens_to_mint)?;
     
-    // Step 7: Emit SubscribeEvent
+    // Step 8: Emit SubscribeEvent
     emit!(SubscribeEvent {
         user: ctx.accounts.user.key(),
         usdc_amount,
         tokens_minted: tokens_to_mint,
-        nav_at_subscription: nav_cents,
+        nav_at_subscription: nav,
     });
     
     msg!(
-        "Subscribe: {} USDC (fee: {}) -> {} tokens at NAV {} cents",
+        "Subscribe: {} USDC (fee: {}) -> {} tokens at NAV {}",
         usdc_amount,
         fee_amount,
         tokens_to_mint,
-        nav_cents
+        nav
     );
     
     Ok(())
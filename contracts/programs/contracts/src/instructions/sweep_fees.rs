@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::RenewraError;
+use crate::events::FeesSweptEvent;
+use crate::state::{FeeVault, Governance};
+
+#[derive(Accounts)]
+pub struct SweepFees<'info> {
+    /// Fund administrator - must match governance.admin_key
+    #[account(
+        constraint = fund_authority.key() == governance.admin_key @ RenewraError::InvalidAuthority
+    )]
+    pub fund_authority: Signer<'info>,
+
+    /// Governance account (treasury authority, bucket destinations)
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Fee vault holding the earmarked bucket balances
+    #[account(
+        mut,
+        seeds = [FeeVault::SEED],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    /// Treasury PDA token account (pays out USDC, authority = governance PDA)
+    #[account(
+        mut,
+        address = governance.treasury @ RenewraError::InvalidTreasury
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Operations bucket destination
+    #[account(
+        mut,
+        address = governance.fee_operations_account @ RenewraError::InvalidFeeDestination,
+        constraint = operations_account.mint == governance.usdc_mint @ RenewraError::InvalidUsdcMint
+    )]
+    pub operations_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Reserve bucket destination
+    #[account(
+        mut,
+        address = governance.fee_reserve_account @ RenewraError::InvalidFeeDestination,
+        constraint = reserve_account.mint == governance.usdc_mint @ RenewraError::InvalidUsdcMint
+    )]
+    pub reserve_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// DAO treasury bucket destination
+    #[account(
+        mut,
+        address = governance.fee_dao_account @ RenewraError::InvalidFeeDestination,
+        constraint = dao_account.mint == governance.usdc_mint @ RenewraError::InvalidUsdcMint
+    )]
+    pub dao_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// USDC mint (decimals for transfer_checked)
+    #[account(
+        address = governance.usdc_mint @ RenewraError::InvalidUsdcMint
+    )]
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<SweepFees>) -> Result<()> {
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    let clock = Clock::get()?;
+    
+    // Step 1: Read bucket balances and make sure the treasury can cover them
+    let operations_amount = fee_vault.operations_pending;
+    let reserve_amount = fee_vault.reserve_pending;
+    let dao_amount = fee_vault.dao_pending;
+    let total = fee_vault.pending_total()?;
+    
+    require!(total > 0, RenewraError::InvalidAmount);
+    require!(
+        ctx.accounts.treasury.amount >= total,
+        RenewraError::InsufficientTreasuryFunds
+    );
+    
+    // Step 2: Clear the buckets before moving funds
+    fee_vault.operations_pending = 0;
+    fee_vault.reserve_pending = 0;
+    fee_vault.dao_pending = 0;
+    fee_vault.total_swept = fee_vault
+        .total_swept
+        .checked_add(total)
+        .ok_or(RenewraError::ArithmeticOverflow)?;
+    
+    // Step 3: Transfer each bucket from treasury using governance PDA as signer
+    let seeds = &[Governance::SEED, &[ctx.accounts.governance.bump]];
+    let signer_seeds = &[&seeds[..]];
+    
+    for (destination, amount) in [
+        (&ctx.accounts.operations_account, operations_amount),
+        (&ctx.accounts.reserve_account, reserve_amount),
+        (&ctx.accounts.dao_account, dao_amount),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury.to_account_info(),
+                mint: ctx.accounts.usdc_mint.to_account_info(),
+                to: destination.to_account_info(),
+                authority: ctx.accounts.governance.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.usdc_mint.decimals)?;
+    }
+    
+    // Step 4: Emit FeesSweptEvent
+    emit!(FeesSweptEvent {
+        operations_amount,
+        reserve_amount,
+        dao_amount,
+        swept_by: ctx.accounts.fund_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!(
+        "Fees swept: {} operations / {} reserve / {} DAO",
+        operations_amount,
+        reserve_amount,
+        dao_amount
+    );
+    
+    Ok(())
+}
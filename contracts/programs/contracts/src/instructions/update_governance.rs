@@ -0,0 +1,261 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::RenewraError;
+use crate::events::GovernanceUpdatedEvent;
+use crate::instructions::accrue_management_fee;
+use crate::state::{Governance, PricingMode};
+
+#[derive(Accounts)]
+pub struct UpdateGovernance<'info> {
+    /// Fund administrator - must match governance.admin_key
+    #[account(
+        constraint = fund_authority.key() == governance.admin_key @ RenewraError::InvalidAuthority
+    )]
+    pub fund_authority: Signer<'info>,
+
+    /// Governance account to update
+    #[account(
+        mut,
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// REI token mint; required only when the management fee or its recipient
+    /// changes after initialize_mint (the fee is settled at the old terms first)
+    #[account(
+        mut,
+        address = governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub reit_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Current fee recipient's REI token account (required alongside reit_mint)
+    #[account(
+        mut,
+        constraint = fee_recipient_account.owner == governance.fee_recipient @ RenewraError::InvalidFeeRecipient,
+        constraint = fee_recipient_account.mint == governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub fee_recipient_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token-2022 program (REI mint)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Parameters for updating governance (None leaves the current value unchanged).
+/// The oracle signer is changed only through rotate_oracle.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateGovernanceParams {
+    pub management_fee_bps: Option<u16>,
+    pub mint_fee_bps: Option<u16>,
+    pub redemption_fee_bps: Option<u16>,
+    pub guardian: Option<Pubkey>,
+    pub max_nav_deviation_bps: Option<u16>,
+    pub nav_deviation_auto_pause: Option<bool>,
+    pub max_nav_age_secs: Option<i64>,
+    pub pricing_mode: Option<PricingMode>,
+    pub twap_window_secs: Option<i64>,
+    pub fee_recipient: Option<Pubkey>,
+    /// Operations / reserve / DAO split in basis points (must sum to 10000)
+    pub fee_split_bps: Option<[u16; 3]>,
+    pub fee_operations_account: Option<Pubkey>,
+    pub fee_reserve_account: Option<Pubkey>,
+    pub fee_dao_account: Option<Pubkey>,
+    /// Subscription limits in USDC (0 disables the limit)
+    pub min_subscription: Option<u64>,
+    pub max_subscription_per_tx: Option<u64>,
+    pub max_investor_deposits: Option<u64>,
+    pub max_total_deposits: Option<u64>,
+    pub compliance_authority: Option<Pubkey>,
+    pub require_kyc: Option<bool>,
+}
+
+pub fn handler(ctx: Context<UpdateGovernance>, params: UpdateGovernanceParams) -> Result<()> {
+    // Charge the management fee due so far at the current rate and recipient
+    // before either can change (nothing is due before the mint exists)
+    let fee_terms_change = params
+        .management_fee_bps
+        .is_some_and(|bps| bps != ctx.accounts.governance.management_fee_bps)
+        || params
+            .fee_recipient
+            .is_some_and(|recipient| recipient != ctx.accounts.governance.fee_recipient);
+    if fee_terms_change && ctx.accounts.governance.reit_mint != Pubkey::default() {
+        let (Some(reit_mint), Some(fee_recipient_account)) =
+            (&ctx.accounts.reit_mint, &ctx.accounts.fee_recipient_account)
+        else {
+            return err!(RenewraError::FeeAccountsRequired);
+        };
+        accrue_management_fee::accrue(
+            &mut ctx.accounts.governance,
+            reit_mint,
+            fee_recipient_account,
+            &ctx.accounts.token_program,
+        )?;
+    }
+    
+    let governance = &mut ctx.accounts.governance;
+    let clock = Clock::get()?;
+    
+    // Step 1: Resolve new values, falling back to current ones
+    let management_fee_bps = params
+        .management_fee_bps
+        .unwrap_or(governance.management_fee_bps);
+    let mint_fee_bps = params.mint_fee_bps.unwrap_or(governance.mint_fee_bps);
+    let redemption_fee_bps = params
+        .redemption_fee_bps
+        .unwrap_or(governance.redemption_fee_bps);
+    let guardian = params.guardian.unwrap_or(governance.guardian);
+    let max_nav_deviation_bps = params
+        .max_nav_deviation_bps
+        .unwrap_or(governance.max_nav_deviation_bps);
+    let nav_deviation_auto_pause = params
+        .nav_deviation_auto_pause
+        .unwrap_or(governance.nav_deviation_auto_pause);
+    let max_nav_age_secs = params
+        .max_nav_age_secs
+        .unwrap_or(governance.max_nav_age_secs);
+    let pricing_mode = params.pricing_mode.unwrap_or(governance.pricing_mode);
+    let twap_window_secs = params
+        .twap_window_secs
+        .unwrap_or(governance.twap_window_secs);
+    let fee_recipient = params.fee_recipient.unwrap_or(governance.fee_recipient);
+    let old_fee_split_bps = [
+        governance.fee_split_operations_bps,
+        governance.fee_split_reserve_bps,
+        governance.fee_split_dao_bps,
+    ];
+    let fee_split_bps = params.fee_split_bps.unwrap_or(old_fee_split_bps);
+    let fee_operations_account = params
+        .fee_operations_account
+        .unwrap_or(governance.fee_operations_account);
+    let fee_reserve_account = params
+        .fee_reserve_account
+        .unwrap_or(governance.fee_reserve_account);
+    let fee_dao_account = params.fee_dao_account.unwrap_or(governance.fee_dao_account);
+    let min_subscription = params
+        .min_subscription
+        .unwrap_or(governance.min_subscription);
+    let max_subscription_per_tx = params
+        .max_subscription_per_tx
+        .unwrap_or(governance.max_subscription_per_tx);
+    let max_investor_deposits = params
+        .max_investor_deposits
+        .unwrap_or(governance.max_investor_deposits);
+    let max_total_deposits = params
+        .max_total_deposits
+        .unwrap_or(governance.max_total_deposits);
+    let compliance_authority = params
+        .compliance_authority
+        .unwrap_or(governance.compliance_authority);
+    let require_kyc = params.require_kyc.unwrap_or(governance.require_kyc);
+    
+    // Step 2: Validate fees against the hard cap
+    require!(
+        management_fee_bps <= Governance::MAX_FEE_BPS
+            && mint_fee_bps <= Governance::MAX_FEE_BPS
+            && redemption_fee_bps <= Governance::MAX_FEE_BPS,
+        RenewraError::FeeTooHigh
+    );
+    
+    // Step 3: Validate the NAV deviation band (non-zero, at most 100%), staleness limit
+    // and TWAP window
+    require!(
+        max_nav_deviation_bps > 0 && max_nav_deviation_bps <= 10_000,
+        RenewraError::InvalidGovernanceParams
+    );
+    require!(
+        max_nav_age_secs > 0 && twap_window_secs > 0,
+        RenewraError::InvalidGovernanceParams
+    );
+    
+    // Step 4: Validate the fee split covers exactly 100%
+    require!(
+        fee_split_bps.iter().map(|bps| *bps as u32).sum::<u32>() == 10_000,
+        RenewraError::InvalidGovernanceParams
+    );
+    
+    // Step 5: Validate the minimum subscription fits under the per-transaction maximum
+    require!(
+        max_subscription_per_tx == 0 || min_subscription <= max_subscription_per_tx,
+        RenewraError::InvalidGovernanceParams
+    );
+    
+    // Step 6: Emit GovernanceUpdatedEvent with old and new values
+    emit!(GovernanceUpdatedEvent {
+        old_management_fee_bps: governance.management_fee_bps,
+        new_management_fee_bps: management_fee_bps,
+        old_mint_fee_bps: governance.mint_fee_bps,
+        new_mint_fee_bps: mint_fee_bps,
+        old_redemption_fee_bps: governance.redemption_fee_bps,
+        new_redemption_fee_bps: redemption_fee_bps,
+        old_guardian: governance.guardian,
+        new_guardian: guardian,
+        old_max_nav_deviation_bps: governance.max_nav_deviation_bps,
+        new_max_nav_deviation_bps: max_nav_deviation_bps,
+        old_nav_deviation_auto_pause: governance.nav_deviation_auto_pause,
+        new_nav_deviation_auto_pause: nav_deviation_auto_pause,
+        old_max_nav_age_secs: governance.max_nav_age_secs,
+        new_max_nav_age_secs: max_nav_age_secs,
+        old_pricing_mode: governance.pricing_mode,
+        new_pricing_mode: pricing_mode,
+        old_twap_window_secs: governance.twap_window_secs,
+        new_twap_window_secs: twap_window_secs,
+        old_fee_recipient: governance.fee_recipient,
+        new_fee_recipient: fee_recipient,
+        old_fee_split_bps,
+        new_fee_split_bps: fee_split_bps,
+        old_fee_operations_account: governance.fee_operations_account,
+        new_fee_operations_account: fee_operations_account,
+        old_fee_reserve_account: governance.fee_reserve_account,
+        new_fee_reserve_account: fee_reserve_account,
+        old_fee_dao_account: governance.fee_dao_account,
+        new_fee_dao_account: fee_dao_account,
+        old_min_subscription: governance.min_subscription,
+        new_min_subscription: min_subscription,
+        old_max_subscription_per_tx: governance.max_subscription_per_tx,
+        new_max_subscription_per_tx: max_subscription_per_tx,
+        old_max_investor_deposits: governance.max_investor_deposits,
+        new_max_investor_deposits: max_investor_deposits,
+        old_max_total_deposits: governance.max_total_deposits,
+        new_max_total_deposits: max_total_deposits,
+        old_compliance_authority: governance.compliance_authority,
+        new_compliance_authority: compliance_authority,
+        old_require_kyc: governance.require_kyc,
+        new_require_kyc: require_kyc,
+        updated_by: ctx.accounts.fund_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    // Step 7: Apply updates
+    governance.management_fee_bps = management_fee_bps;
+    governance.mint_fee_bps = mint_fee_bps;
+    governance.redemption_fee_bps = redemption_fee_bps;
+    governance.guardian = guardian;
+    governance.max_nav_deviation_bps = max_nav_deviation_bps;
+    governance.nav_deviation_auto_pause = nav_deviation_auto_pause;
+    governance.max_nav_age_secs = max_nav_age_secs;
+    governance.pricing_mode = pricing_mode;
+    governance.twap_window_secs = twap_window_secs;
+    governance.fee_recipient = fee_recipient;
+    governance.fee_split_operations_bps = fee_split_bps[0];
+    governance.fee_split_reserve_bps = fee_split_bps[1];
+    governance.fee_split_dao_bps = fee_split_bps[2];
+    governance.fee_operations_account = fee_operations_account;
+    governance.fee_reserve_account = fee_reserve_account;
+    governance.fee_dao_account = fee_dao_account;
+    governance.min_subscription = min_subscription;
+    governance.max_subscription_per_tx = max_subscription_per_tx;
+    governance.max_investor_deposits = max_investor_deposits;
+    governance.max_total_deposits = max_total_deposits;
+    governance.compliance_authority = compliance_authority;
+    governance.require_kyc = require_kyc;
+    
+    msg!(
+        "Governance updated: fees {}/{}/{} bps (mgmt/mint/redeem)",
+        management_fee_bps,
+        mint_fee_bps,
+        redemption_fee_bps
+    );
+    
+    Ok(())
+}
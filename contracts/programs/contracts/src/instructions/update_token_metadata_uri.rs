@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as MintState,
+};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_2022_extensions::{token_metadata_update_field, TokenMetadataUpdateField};
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::RenewraError;
+use crate::events::TokenMetadataUriUpdatedEvent;
+use crate::instructions::initialize_token_metadata::{top_up_mint_rent, validate_uri};
+use crate::state::Governance;
+
+#[derive(Accounts)]
+pub struct UpdateTokenMetadataUri<'info> {
+    /// Fund administrator - must match governance.admin_key (pays any rent top-up)
+    #[account(
+        mut,
+        constraint = fund_authority.key() == governance.admin_key @ RenewraError::InvalidAuthority
+    )]
+    pub fund_authority: Signer<'info>,
+
+    /// Governance account (metadata update authority)
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// REI token mint holding the metadata
+    #[account(
+        mut,
+        address = governance.reit_mint @ RenewraError::InvalidReitMint
+    )]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateTokenMetadataUri>, uri: String) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let mint_info = ctx.accounts.reit_mint.to_account_info();
+    
+    // Step 1: Validate the URI
+    validate_uri(&uri)?;
+    
+    // Step 2: Size the metadata entry with the new URI and fund any growth
+    let (old_uri, new_len) = {
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+        let mut metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+        let old_tlv_len = metadata.tlv_size_of()?;
+        let old_uri = std::mem::replace(&mut metadata.uri, uri.clone());
+        let new_len = (data.len() - old_tlv_len)
+            .checked_add(metadata.tlv_size_of()?)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        (old_uri, new_len)
+    };
+    top_up_mint_rent(
+        &ctx.accounts.fund_authority,
+        &mint_info,
+        &ctx.accounts.system_program,
+        new_len,
+    )?;
+    
+    // Step 3: Update the URI using governance PDA as update authority
+    let seeds = &[Governance::SEED, &[governance.bump]];
+    let signer_seeds = &[&seeds[..]];
+    
+    let update_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TokenMetadataUpdateField {
+            token_program_id: ctx.accounts.token_program.to_account_info(),
+            metadata: mint_info.clone(),
+            update_authority: governance.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_metadata_update_field(update_ctx, Field::Uri, uri.clone())?;
+    
+    // Step 4: Emit TokenMetadataUriUpdatedEvent
+    emit!(TokenMetadataUriUpdatedEvent {
+        mint: mint_info.key(),
+        old_uri,
+        new_uri: uri.clone(),
+        updated_by: ctx.accounts.fund_authority.key(),
+    });
+    
+    msg!("Token metadata URI updated: {}", uri);
+    
+    Ok(())
+}
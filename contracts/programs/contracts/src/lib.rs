@@ -6,6 +6,7 @@ pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::YieldMode;
 
 declare_id!("5nU2nHv2Pw9bWWL2BsTotX6mDaP1fTj1EZ7JMXAe6T5Z");
 
@@ -26,23 +27,164 @@ pub mod renewra {
         instructions::initialize_mint::handler(ctx)
     }
 
-    /// Submit a new NAV value (oracle signer only)
+    /// Submit a NAV value into the pending round (oracle signers only)
     pub fn submit_nav(ctx: Context<SubmitNav>, new_nav: u64) -> Result<()> {
         instructions::submit_nav::handler(ctx, new_nav)
     }
 
-    /// Subscribe to the fund by depositing USDC and receiving REI tokens
-    pub fn subscribe(ctx: Context<Subscribe>, usdc_amount: u64) -> Result<()> {
-        instructions::subscribe::handler(ctx, usdc_amount)
+    /// Subscribe to the fund by depositing USDC and receiving at least `min_tokens_out` REI tokens
+    pub fn subscribe(ctx: Context<Subscribe>, usdc_amount: u64, min_tokens_out: u64) -> Result<()> {
+        instructions::subscribe::handler(ctx, usdc_amount, min_tokens_out)
     }
 
-    /// Distribute yield to token holders pro-rata (admin only)
-    pub fn distribute_yield(ctx: Context<DistributeYield>, yield_amount: u64) -> Result<()> {
-        instructions::distribute_yield::handler(ctx, yield_amount)
+    /// Distribute yield to token holders pro-rata as claimable cash, or reinvest it into NAV (admin only)
+    pub fn distribute_yield(
+        ctx: Context<DistributeYield>,
+        yield_amount: u64,
+        mode: YieldMode,
+    ) -> Result<()> {
+        instructions::distribute_yield::handler(ctx, yield_amount, mode)
     }
 
     /// Request redemption of REI tokens (queued for settlement)
-    pub fn request_redeem(ctx: Context<RequestRedeem>, token_amount: u64) -> Result<()> {
+    pub fn request_redeem<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestRedeem<'info>>,
+        token_amount: u64,
+    ) -> Result<()> {
         instructions::request_redeem::handler(ctx, token_amount)
     }
+
+    /// Approve a pending redemption request (admin only)
+    pub fn approve_redeem(ctx: Context<ApproveRedeem>, request_id: u64) -> Result<()> {
+        instructions::approve_redeem::handler(ctx, request_id)
+    }
+
+    /// Settle an approved redemption: burn REI and pay out at least `min_usdc_out` USDC from the treasury
+    pub fn settle_redeem(
+        ctx: Context<SettleRedeem>,
+        request_id: u64,
+        min_usdc_out: u64,
+    ) -> Result<()> {
+        instructions::settle_redeem::handler(ctx, request_id, min_usdc_out)
+    }
+
+    /// Cancel a pending redemption request and return escrowed REI (requester only)
+    pub fn cancel_redeem<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelRedeem<'info>>,
+        request_id: u64,
+    ) -> Result<()> {
+        instructions::cancel_redeem::handler(ctx, request_id)
+    }
+
+    /// Pause or unpause the fund (admin, or guardian for pausing only)
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_paused::handler(ctx, paused)
+    }
+
+    /// Update fees and privileged keys (admin only)
+    pub fn update_governance(
+        ctx: Context<UpdateGovernance>,
+        params: UpdateGovernanceParams,
+    ) -> Result<()> {
+        instructions::update_governance::handler(ctx, params)
+    }
+
+    /// Propose a new admin key; takes effect once accepted (admin only)
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin::handler(ctx, new_admin)
+    }
+
+    /// Accept a pending admin proposal (proposed admin only)
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::handler(ctx)
+    }
+
+    /// Schedule an oracle signer rotation with an overlap window; one at a time (admin only)
+    pub fn rotate_oracle(
+        ctx: Context<RotateOracle>,
+        next_oracle_signer: Pubkey,
+        activation_slot: u64,
+        overlap_slots: u64,
+    ) -> Result<()> {
+        instructions::rotate_oracle::handler(ctx, next_oracle_signer, activation_slot, overlap_slots)
+    }
+
+    /// Configure the M-of-N oracle set for NAV rounds (admin only)
+    pub fn configure_oracles(
+        ctx: Context<ConfigureOracles>,
+        params: ConfigureOraclesParams,
+    ) -> Result<()> {
+        instructions::configure_oracles::handler(ctx, params)
+    }
+
+    /// Return the time-weighted average NAV over `window_secs` (read-only, CPI friendly)
+    pub fn get_twap(ctx: Context<GetTwap>, window_secs: i64) -> Result<u64> {
+        instructions::get_twap::handler(ctx, window_secs)
+    }
+
+    /// Baseline upgrade step 1: rewrite governance and the redemption queue in the current
+    /// layouts and move fund USDC into the treasury PDA (legacy admin only)
+    pub fn migrate_governance(ctx: Context<MigrateGovernance>) -> Result<()> {
+        instructions::migrate_governance::handler(ctx)
+    }
+
+    /// Baseline upgrade step 2: rescale the whole-cent NAV oracle to 6-decimal fixed point
+    /// and seed NAV history (admin only)
+    pub fn migrate_nav_precision(ctx: Context<MigrateNavPrecision>) -> Result<()> {
+        instructions::migrate_nav_precision::handler(ctx)
+    }
+
+    /// Baseline upgrade step 3: create the oracle config, yield state and fee vault (admin only)
+    pub fn migrate_fund_accounts(ctx: Context<MigrateFundAccounts>) -> Result<()> {
+        instructions::migrate_fund_accounts::handler(ctx)
+    }
+
+    /// Claim USDC yield accrued through the reward index since the last checkpoint
+    pub fn claim_yield(ctx: Context<ClaimYield>) -> Result<()> {
+        instructions::claim_yield::handler(ctx)
+    }
+
+    /// Mint the management fee accrued since the last accrual to the fee recipient (permissionless crank)
+    pub fn accrue_management_fee(ctx: Context<AccrueManagementFee>) -> Result<()> {
+        instructions::accrue_management_fee::handler(ctx)
+    }
+
+    /// Pay earmarked fees out of the treasury to the operations / reserve / DAO accounts (admin only)
+    pub fn sweep_fees(ctx: Context<SweepFees>) -> Result<()> {
+        instructions::sweep_fees::handler(ctx)
+    }
+
+    /// Issue or renew an investor KYC attestation (compliance authority only)
+    pub fn issue_attestation(
+        ctx: Context<IssueAttestation>,
+        params: IssueAttestationParams,
+    ) -> Result<()> {
+        instructions::issue_attestation::handler(ctx, params)
+    }
+
+    /// Revoke an investor KYC attestation (compliance authority only)
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        instructions::revoke_attestation::handler(ctx)
+    }
+
+    /// Write Token-2022 name / symbol / URI metadata onto the REI mint (admin only)
+    pub fn initialize_token_metadata(
+        ctx: Context<InitializeTokenMetadata>,
+        uri: String,
+    ) -> Result<()> {
+        instructions::initialize_token_metadata::handler(ctx, uri)
+    }
+
+    /// Point the REI metadata at a new URI (admin only)
+    pub fn update_token_metadata_uri(
+        ctx: Context<UpdateTokenMetadataUri>,
+        uri: String,
+    ) -> Result<()> {
+        instructions::update_token_metadata_uri::handler(ctx, uri)
+    }
+
+    /// Checkpoint sender and receiver yield around an REI transfer (transfer hook only)
+    pub fn checkpoint_transfer(ctx: Context<CheckpointTransfer>, amount: u64) -> Result<()> {
+        instructions::checkpoint_transfer::handler(ctx, amount)
+    }
 }
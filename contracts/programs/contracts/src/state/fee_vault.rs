@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+use crate::state::{Governance, YieldState};
+
+/// FeeVault PDA earmarks mint and redemption fees held in the treasury,
+/// split into operations / reserve / DAO buckets until sweep_fees pays them out.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeVault {
+    /// USDC owed to the operations bucket
+    pub operations_pending: u64,
+    
+    /// USDC owed to the reserve bucket
+    pub reserve_pending: u64,
+    
+    /// USDC owed to the DAO treasury bucket
+    pub dao_pending: u64,
+    
+    /// Total USDC fees collected
+    pub total_collected: u64,
+    
+    /// Total USDC swept out to bucket destinations
+    pub total_swept: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl FeeVault {
+    /// PDA seed for fee_vault account
+    pub const SEED: &'static [u8] = b"fee_vault";
+    
+    /// Space: 8 (discriminator) + 8 + 8 + 8 + 8 + 8 + 1 = 49 bytes
+    pub const SPACE: usize = 8 + 8 + 8 + 8 + 8 + 8 + 1;
+    
+    /// Split `fee` by the governance ratios (rounded down); the DAO bucket takes the remainder
+    pub fn record(&mut self, fee: u64, governance: &Governance) -> Result<()> {
+        let operations = Self::share(fee, governance.fee_split_operations_bps)?;
+        let reserve = Self::share(fee, governance.fee_split_reserve_bps)?;
+        let dao = fee
+            .checked_sub(operations)
+            .and_then(|rest| rest.checked_sub(reserve))
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        
+        self.operations_pending = self
+            .operations_pending
+            .checked_add(operations)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        self.reserve_pending = self
+            .reserve_pending
+            .checked_add(reserve)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        self.dao_pending = self
+            .dao_pending
+            .checked_add(dao)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        self.total_collected = self
+            .total_collected
+            .checked_add(fee)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        
+        Ok(())
+    }
+    
+    /// USDC owed to the three buckets and not yet swept
+    pub fn pending_total(&self) -> Result<u64> {
+        self.operations_pending
+            .checked_add(self.reserve_pending)
+            .and_then(|sum| sum.checked_add(self.dao_pending))
+            .ok_or(RenewraError::ArithmeticOverflow.into())
+    }
+    
+    /// Treasury USDC free for payouts: `treasury_amount` less the pending fee buckets
+    /// and the yield distributed to holders but not yet claimed
+    pub fn available_liquidity(&self, treasury_amount: u64, yield_state: &YieldState) -> Result<u64> {
+        let earmarked = self
+            .pending_total()?
+            .checked_add(yield_state.unclaimed())
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        Ok(treasury_amount.saturating_sub(earmarked))
+    }
+    
+    fn share(fee: u64, bps: u16) -> Result<u64> {
+        let share = (fee as u128)
+            .checked_mul(bps as u128)
+            .ok_or(RenewraError::ArithmeticOverflow)?
+            / 10_000;
+        u64::try_from(share).map_err(|_| RenewraError::ArithmeticOverflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn redemption_cannot_dip_into_earmarked_fees_or_owed_yield() {
+        // 305 USDC of fees not yet swept, including the 5 USDC fee of the
+        // redemption being settled (earmarked before the liquidity check)
+        let fee_vault = FeeVault {
+            operations_pending: 152_500_000,
+            reserve_pending: 91_500_000,
+            dao_pending: 61_000_000,
+            total_collected: 305_000_000,
+            total_swept: 0,
+            bump: 255,
+        };
+        let yield_state = YieldState {
+            reward_per_token: 0,
+            total_distributed: 300_000_000,
+            total_claimed: 100_000_000,
+            total_reinvested: 0,
+            earning_supply: 0,
+            bump: 255,
+        };
+        
+        // 1,000 USDC in the treasury, 200 of it owed to holders as unclaimed yield
+        let treasury_amount = 1_000_000_000;
+        let available = fee_vault.available_liquidity(treasury_amount, &yield_state).unwrap();
+        assert_eq!(available, 495_000_000);
+        
+        // A 600 USDC payout fits the raw balance but would spend earmarked fees
+        let net_usdc = 600_000_000;
+        assert!(treasury_amount >= net_usdc);
+        assert!(available < net_usdc);
+        
+        // Nothing is available once earmarks exceed the balance
+        assert_eq!(fee_vault.available_liquidity(400_000_000, &yield_state).unwrap(), 0);
+    }
+}
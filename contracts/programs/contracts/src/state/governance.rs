@@ -1,4 +1,18 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::RenewraError;
+use crate::state::{InvestorAttestation, InvestorPosition};
+
+/// NAV used to price subscriptions and redemptions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PricingMode {
+    /// Latest oracle NAV
+    Spot,
+    /// Time-weighted average NAV over governance.twap_window_secs
+    Twap,
+}
 
 /// Governance account stores fund-wide parameters and admin keys.
 /// Initialized once during fund setup.
@@ -23,6 +37,90 @@ pub struct Governance {
     /// Whether the fund is paused (emergency stop)
     pub paused: bool,
     
+    /// Guardian public key that can pause (but not unpause) the fund
+    pub guardian: Pubkey,
+    
+    /// Proposed new admin awaiting acceptance (Pubkey::default() when none)
+    pub pending_admin: Pubkey,
+    
+    /// Incoming oracle signer during a key rotation (Pubkey::default() when none)
+    pub next_oracle_signer: Pubkey,
+    
+    /// Slot from which next_oracle_signer is accepted
+    pub oracle_activation_slot: u64,
+    
+    /// Number of slots after activation during which the old signer is still accepted
+    pub oracle_overlap_slots: u64,
+    
+    /// Maximum NAV change per update in basis points (circuit breaker band)
+    pub max_nav_deviation_bps: u16,
+    
+    /// On a NAV deviation breach: pause the fund (true) or reject the update (false)
+    pub nav_deviation_auto_pause: bool,
+    
+    /// Maximum age of the NAV (seconds) accepted when pricing subscriptions and redemptions
+    pub max_nav_age_secs: i64,
+    
+    /// Whether subscribe and redemption settlement price at spot NAV or TWAP
+    pub pricing_mode: PricingMode,
+    
+    /// Averaging window (seconds) used in TWAP pricing mode
+    pub twap_window_secs: i64,
+    
+    /// Treasury PDA token account holding fund USDC (authority = governance PDA)
+    pub treasury: Pubkey,
+    
+    /// Accepted USDC mint for subscriptions and payouts
+    pub usdc_mint: Pubkey,
+    
+    /// REI token mint (set by initialize_mint, default until then)
+    pub reit_mint: Pubkey,
+    
+    /// Wallet whose REI token account receives minted management fees
+    pub fee_recipient: Pubkey,
+    
+    /// Unix timestamp up to which the management fee has been charged
+    pub last_fee_accrual: i64,
+    
+    /// Share of collected USDC fees routed to operations (basis points)
+    pub fee_split_operations_bps: u16,
+    
+    /// Share of collected USDC fees routed to the reserve (basis points)
+    pub fee_split_reserve_bps: u16,
+    
+    /// Share of collected USDC fees routed to the DAO treasury (basis points)
+    pub fee_split_dao_bps: u16,
+    
+    /// USDC token account receiving the operations bucket
+    pub fee_operations_account: Pubkey,
+    
+    /// USDC token account receiving the reserve bucket
+    pub fee_reserve_account: Pubkey,
+    
+    /// USDC token account receiving the DAO treasury bucket
+    pub fee_dao_account: Pubkey,
+    
+    /// Smallest accepted subscription in USDC (0 = no minimum)
+    pub min_subscription: u64,
+    
+    /// Largest accepted single subscription in USDC (0 = no limit)
+    pub max_subscription_per_tx: u64,
+    
+    /// Lifetime USDC an investor may subscribe (0 = no limit)
+    pub max_investor_deposits: u64,
+    
+    /// Fund-wide cap on outstanding deposits (0 = no limit)
+    pub max_total_deposits: u64,
+    
+    /// Outstanding deposits: USDC subscribed minus gross USDC of settled redemptions
+    pub total_deposits: u64,
+    
+    /// Key allowed to issue and revoke investor attestations
+    pub compliance_authority: Pubkey,
+    
+    /// Whether subscribe and redemption require a valid investor attestation
+    pub require_kyc: bool,
+    
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
@@ -31,6 +129,464 @@ impl Governance {
     /// PDA seed for governance account
     pub const SEED: &'static [u8] = b"governance";
     
-    /// Space: 8 (discriminator) + 32 + 32 + 2 + 2 + 2 + 1 + 1 = 80 bytes
-    pub const SPACE: usize = 8 + 32 + 32 + 2 + 2 + 2 + 1 + 1;
+    /// PDA seed for the treasury USDC token account
+    pub const TREASURY_SEED: &'static [u8] = b"treasury";
+    
+    /// Hard cap for any fee parameter in basis points (10%)
+    pub const MAX_FEE_BPS: u16 = 1000;
+    
+    /// Default NAV deviation band (whitepaper: max 5% change per update)
+    pub const DEFAULT_MAX_NAV_DEVIATION_BPS: u16 = 500;
+    
+    /// Default NAV staleness limit (oracle updates hourly, allow two missed updates)
+    pub const DEFAULT_MAX_NAV_AGE_SECS: i64 = 3 * 60 * 60;
+    
+    /// Default TWAP averaging window (6 hours of hourly oracle updates)
+    pub const DEFAULT_TWAP_WINDOW_SECS: i64 = 6 * 60 * 60;
+    
+    /// Management fee accrues in whole days
+    pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+    
+    /// Annual management fee is spread over 365 daily accruals
+    pub const DAYS_PER_YEAR: u128 = 365;
+    
+    /// Default fee split (whitepaper: 50% operations, 30% reserve, 20% DAO)
+    pub const DEFAULT_FEE_SPLIT_OPERATIONS_BPS: u16 = 5000;
+    pub const DEFAULT_FEE_SPLIT_RESERVE_BPS: u16 = 3000;
+    pub const DEFAULT_FEE_SPLIT_DAO_BPS: u16 = 2000;
+    
+    /// Space: 8 (discriminator) + 32 + 32 + 2 + 2 + 2 + 1 + 32 + 32 + 32 + 8 + 8 + 2 + 1 + 8 + 1 + 8
+    /// + 32 + 32 + 32 + 32 + 8 + 2 + 2 + 2 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 1 + 1 = 523 bytes
+    pub const SPACE: usize = 8 + 32 + 32 + 2 + 2 + 2 + 1 + 32 + 32 + 32 + 8 + 8 + 2 + 1 + 8 + 1 + 8
+        + 32 + 32 + 32 + 32 + 8 + 2 + 2 + 2 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 1 + 1;
+    
+    /// Fee of `fee_bps` on `amount`, rounded up in favour of the fund
+    pub fn fee_for(amount: u64, fee_bps: u16) -> Result<u64> {
+        let fee = (amount as u128)
+            .checked_mul(fee_bps as u128)
+            .ok_or(RenewraError::ArithmeticOverflow)?
+            .div_ceil(10_000);
+        u64::try_from(fee).map_err(|_| RenewraError::ArithmeticOverflow.into())
+    }
+    
+    /// Management fee in REI for the whole days elapsed since the last accrual:
+    /// ceil(supply * management_fee_bps * days / (10000 * 365)). Advances
+    /// last_fee_accrual by the days charged so partial days carry over.
+    pub fn accrue_management_fee(&mut self, total_supply: u64, now: i64) -> Result<u64> {
+        let days = now.saturating_sub(self.last_fee_accrual) / Self::SECONDS_PER_DAY;
+        if days <= 0 {
+            return Ok(0);
+        }
+        
+        self.last_fee_accrual = self
+            .last_fee_accrual
+            .checked_add(days * Self::SECONDS_PER_DAY)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        
+        let fee = (total_supply as u128)
+            .checked_mul(self.management_fee_bps as u128)
+            .ok_or(RenewraError::ArithmeticOverflow)?
+            .checked_mul(days as u128)
+            .ok_or(RenewraError::ArithmeticOverflow)?
+            .div_ceil(10_000 * Self::DAYS_PER_YEAR);
+        u64::try_from(fee).map_err(|_| RenewraError::ArithmeticOverflow.into())
+    }
+    
+    /// When KYC is required, fail unless a valid attestation was supplied
+    pub fn check_kyc(&self, attestation: Option<&InvestorAttestation>, now: i64) -> Result<()> {
+        if !self.require_kyc {
+            return Ok(());
+        }
+        attestation
+            .ok_or(RenewraError::KycRequired)?
+            .validate(now)
+    }
+    
+    /// Whether `account` is its owner's associated REI account. UserYield is kept
+    /// per wallet, so only this one account per holder is checkpointed.
+    pub fn is_reit_ata(&self, account: &InterfaceAccount<TokenAccount>) -> bool {
+        let token_program = account.to_account_info().owner;
+        account.key()
+            == get_associated_token_address_with_program_id(&account.owner, &self.reit_mint, token_program)
+    }
+    
+    /// Enforce the subscription limits and count `usdc_amount` toward the
+    /// investor's lifetime cap and the fund-wide deposit cap
+    pub fn record_subscription(
+        &mut self,
+        position: &mut InvestorPosition,
+        usdc_amount: u64,
+    ) -> Result<()> {
+        require!(
+            usdc_amount >= self.min_subscription,
+            RenewraError::BelowMinSubscription
+        );
+        require!(
+            self.max_subscription_per_tx == 0 || usdc_amount <= self.max_subscription_per_tx,
+            RenewraError::SubscriptionTooLarge
+        );
+        
+        let investor_total = position
+            .total_deposited
+            .checked_add(usdc_amount)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        require!(
+            self.max_investor_deposits == 0 || investor_total <= self.max_investor_deposits,
+            RenewraError::InvestorCapExceeded
+        );
+        
+        let fund_total = self
+            .total_deposits
+            .checked_add(usdc_amount)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        require!(
+            self.max_total_deposits == 0 || fund_total <= self.max_total_deposits,
+            RenewraError::FundCapExceeded
+        );
+        
+        position.total_deposited = investor_total;
+        self.total_deposits = fund_total;
+        Ok(())
+    }
+    
+    /// Whether `key` may submit NAV at `slot`, honouring any in-flight oracle rotation.
+    /// The old signer is accepted until activation + overlap, the new one from activation.
+    pub fn is_oracle_signer(&self, key: &Pubkey, slot: u64) -> bool {
+        if self.next_oracle_signer == Pubkey::default() {
+            return *key == self.oracle_signer;
+        }
+        
+        let cutoff_slot = self
+            .oracle_activation_slot
+            .saturating_add(self.oracle_overlap_slots);
+        
+        (*key == self.oracle_signer && slot < cutoff_slot)
+            || (*key == self.next_oracle_signer && slot >= self.oracle_activation_slot)
+    }
+    
+    /// Promote next_oracle_signer once the overlap window has ended.
+    /// Returns true if a rotation was completed.
+    pub fn complete_oracle_rotation(&mut self, slot: u64) -> bool {
+        if self.next_oracle_signer == Pubkey::default() {
+            return false;
+        }
+        
+        let cutoff_slot = self
+            .oracle_activation_slot
+            .saturating_add(self.oracle_overlap_slots);
+        if slot < cutoff_slot {
+            return false;
+        }
+        
+        self.oracle_signer = self.next_oracle_signer;
+        self.next_oracle_signer = Pubkey::default();
+        self.oracle_activation_slot = 0;
+        self.oracle_overlap_slots = 0;
+        true
+    }
+    
+    /// Schedule `next_oracle_signer` to take over at `activation_slot` (no earlier than
+    /// `slot`), keeping the current signer valid for `overlap_slots` after that. Fails
+    /// while another rotation is still in its overlap window, so a scheduled key is
+    /// never replaced and the old key's cutoff never moves. Returns the activation slot.
+    pub fn schedule_oracle_rotation(
+        &mut self,
+        next_oracle_signer: Pubkey,
+        activation_slot: u64,
+        overlap_slots: u64,
+        slot: u64,
+    ) -> Result<u64> {
+        // Fold in any previous rotation whose overlap window has already ended
+        self.complete_oracle_rotation(slot);
+        require!(
+            self.next_oracle_signer == Pubkey::default(),
+            RenewraError::OracleRotationInProgress
+        );
+        require!(
+            next_oracle_signer != Pubkey::default() && next_oracle_signer != self.oracle_signer,
+            RenewraError::InvalidOracleRotation
+        );
+        
+        // Activation in the past means immediately
+        let activation_slot = activation_slot.max(slot);
+        self.next_oracle_signer = next_oracle_signer;
+        self.oracle_activation_slot = activation_slot;
+        self.oracle_overlap_slots = overlap_slots;
+        Ok(activation_slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn governance() -> Governance {
+        Governance {
+            admin_key: Pubkey::new_unique(),
+            oracle_signer: Pubkey::new_unique(),
+            management_fee_bps: 50,
+            mint_fee_bps: 25,
+            redemption_fee_bps: 25,
+            paused: false,
+            guardian: Pubkey::new_unique(),
+            pending_admin: Pubkey::default(),
+            next_oracle_signer: Pubkey::default(),
+            oracle_activation_slot: 0,
+            oracle_overlap_slots: 0,
+            max_nav_deviation_bps: Governance::DEFAULT_MAX_NAV_DEVIATION_BPS,
+            nav_deviation_auto_pause: false,
+            max_nav_age_secs: Governance::DEFAULT_MAX_NAV_AGE_SECS,
+            pricing_mode: PricingMode::Spot,
+            twap_window_secs: Governance::DEFAULT_TWAP_WINDOW_SECS,
+            treasury: Pubkey::new_unique(),
+            usdc_mint: Pubkey::new_unique(),
+            reit_mint: Pubkey::new_unique(),
+            fee_recipient: Pubkey::new_unique(),
+            last_fee_accrual: 0,
+            fee_split_operations_bps: Governance::DEFAULT_FEE_SPLIT_OPERATIONS_BPS,
+            fee_split_reserve_bps: Governance::DEFAULT_FEE_SPLIT_RESERVE_BPS,
+            fee_split_dao_bps: Governance::DEFAULT_FEE_SPLIT_DAO_BPS,
+            fee_operations_account: Pubkey::new_unique(),
+            fee_reserve_account: Pubkey::new_unique(),
+            fee_dao_account: Pubkey::new_unique(),
+            min_subscription: 0,
+            max_subscription_per_tx: 0,
+            max_investor_deposits: 0,
+            max_total_deposits: 0,
+            total_deposits: 0,
+            compliance_authority: Pubkey::new_unique(),
+            require_kyc: false,
+            bump: 255,
+        }
+    }
+    
+    #[test]
+    fn oracle_rotation_accepts_both_keys_only_during_overlap() {
+        let mut governance = governance();
+        let old_signer = governance.oracle_signer;
+        let new_signer = Pubkey::new_unique();
+        
+        // Active from slot 100, old key accepted until slot 150
+        assert_eq!(governance.schedule_oracle_rotation(new_signer, 100, 50, 90).unwrap(), 100);
+        
+        assert!(governance.is_oracle_signer(&old_signer, 99));
+        assert!(!governance.is_oracle_signer(&new_signer, 99));
+        assert!(governance.is_oracle_signer(&old_signer, 149));
+        assert!(governance.is_oracle_signer(&new_signer, 100));
+        assert!(!governance.is_oracle_signer(&old_signer, 150));
+        assert!(governance.is_oracle_signer(&new_signer, 150));
+        assert!(!governance.is_oracle_signer(&Pubkey::new_unique(), 120));
+        
+        // Nothing to complete until the overlap window has ended
+        assert!(!governance.complete_oracle_rotation(149));
+        assert_eq!(governance.oracle_signer, old_signer);
+        
+        assert!(governance.complete_oracle_rotation(150));
+        assert_eq!(governance.oracle_signer, new_signer);
+        assert_eq!(governance.next_oracle_signer, Pubkey::default());
+        assert_eq!(governance.oracle_activation_slot, 0);
+        assert_eq!(governance.oracle_overlap_slots, 0);
+        assert!(!governance.complete_oracle_rotation(151));
+        
+        // Past the rotation only the new key signs
+        assert!(governance.is_oracle_signer(&new_signer, 151));
+        assert!(!governance.is_oracle_signer(&old_signer, 151));
+    }
+    
+    #[test]
+    fn oracle_rotation_cannot_be_replaced_while_in_flight() {
+        let mut governance = governance();
+        let old_signer = governance.oracle_signer;
+        let new_signer = Pubkey::new_unique();
+        let other_signer = Pubkey::new_unique();
+        
+        governance.schedule_oracle_rotation(new_signer, 100, 50, 90).unwrap();
+        
+        // Neither before activation nor during the overlap window
+        assert_eq!(
+            governance.schedule_oracle_rotation(other_signer, 200, 50, 95).unwrap_err(),
+            RenewraError::OracleRotationInProgress.into()
+        );
+        assert_eq!(
+            governance.schedule_oracle_rotation(other_signer, 200, 50, 149).unwrap_err(),
+            RenewraError::OracleRotationInProgress.into()
+        );
+        assert_eq!(governance.oracle_signer, old_signer);
+        assert_eq!(governance.next_oracle_signer, new_signer);
+        assert_eq!(governance.oracle_activation_slot, 100);
+        assert_eq!(governance.oracle_overlap_slots, 50);
+        
+        // Once the window ends the pending rotation is completed first; a past
+        // activation slot means immediately
+        assert_eq!(governance.schedule_oracle_rotation(other_signer, 0, 10, 160).unwrap(), 160);
+        assert_eq!(governance.oracle_signer, new_signer);
+        assert_eq!(governance.next_oracle_signer, other_signer);
+    }
+    
+    #[test]
+    fn oracle_rotation_rejects_default_or_current_key() {
+        let mut governance = governance();
+        let current = governance.oracle_signer;
+        
+        assert_eq!(
+            governance.schedule_oracle_rotation(Pubkey::default(), 100, 50, 90).unwrap_err(),
+            RenewraError::InvalidOracleRotation.into()
+        );
+        assert_eq!(
+            governance.schedule_oracle_rotation(current, 100, 50, 90).unwrap_err(),
+            RenewraError::InvalidOracleRotation.into()
+        );
+        assert_eq!(governance.next_oracle_signer, Pubkey::default());
+    }
+    
+    #[test]
+    fn fees_round_up_in_favour_of_the_fund() {
+        // 0.25% of 1 USDC is exact
+        assert_eq!(Governance::fee_for(1_000_000, 25).unwrap(), 2_500);
+        
+        // Any remainder rounds up to the next base unit
+        assert_eq!(Governance::fee_for(1_000_001, 25).unwrap(), 2_501);
+        assert_eq!(Governance::fee_for(1, 25).unwrap(), 1);
+        
+        // No fee charged on zero amounts or at 0 bps
+        assert_eq!(Governance::fee_for(0, 25).unwrap(), 0);
+        assert_eq!(Governance::fee_for(1_000_000, 0).unwrap(), 0);
+        
+        // The largest fee on the largest amount still fits
+        assert_eq!(
+            Governance::fee_for(u64::MAX, Governance::MAX_FEE_BPS).unwrap(),
+            u64::MAX.div_ceil(10)
+        );
+    }
+    
+    #[test]
+    fn management_fee_accrues_in_whole_days() {
+        let mut governance = governance();
+        let day = Governance::SECONDS_PER_DAY;
+        let supply = 1_000_000_000_000; // 1M REI
+        
+        // Nothing is due before a full day has passed
+        assert_eq!(governance.accrue_management_fee(supply, day - 1).unwrap(), 0);
+        assert_eq!(governance.last_fee_accrual, 0);
+        
+        // One day of 0.5% a year, rounded up; the extra hour carries over
+        assert_eq!(governance.accrue_management_fee(supply, day + 3_600).unwrap(), 13_698_631);
+        assert_eq!(governance.last_fee_accrual, day);
+        
+        // The carried hour completes the second day
+        assert_eq!(governance.accrue_management_fee(supply, 2 * day).unwrap(), 13_698_631);
+        assert_eq!(governance.last_fee_accrual, 2 * day);
+        
+        // A full year missed at once charges exactly the annual rate
+        assert_eq!(governance.accrue_management_fee(supply, 367 * day).unwrap(), 5_000_000_000);
+        assert_eq!(governance.last_fee_accrual, 367 * day);
+        
+        // A clock behind the last accrual charges nothing
+        assert_eq!(governance.accrue_management_fee(supply, 300 * day).unwrap(), 0);
+        assert_eq!(governance.last_fee_accrual, 367 * day);
+    }
+    
+    fn position() -> InvestorPosition {
+        InvestorPosition {
+            owner: Pubkey::new_unique(),
+            total_deposited: 0,
+            last_subscribed_at: 0,
+            bump: 255,
+        }
+    }
+    
+    #[test]
+    fn subscription_caps_are_enforced_and_counted() {
+        let mut governance = governance();
+        governance.min_subscription = 10_000_000;
+        governance.max_subscription_per_tx = 1_000_000_000;
+        governance.max_investor_deposits = 1_500_000_000;
+        governance.max_total_deposits = 2_000_000_000;
+        let mut alice = position();
+        let mut bob = position();
+        
+        assert_eq!(
+            governance.record_subscription(&mut alice, 9_999_999).unwrap_err(),
+            RenewraError::BelowMinSubscription.into()
+        );
+        assert_eq!(
+            governance.record_subscription(&mut alice, 1_000_000_001).unwrap_err(),
+            RenewraError::SubscriptionTooLarge.into()
+        );
+        
+        governance.record_subscription(&mut alice, 1_000_000_000).unwrap();
+        assert_eq!(
+            governance.record_subscription(&mut alice, 500_000_001).unwrap_err(),
+            RenewraError::InvestorCapExceeded.into()
+        );
+        governance.record_subscription(&mut alice, 500_000_000).unwrap();
+        
+        // Fund cap applies across investors
+        assert_eq!(
+            governance.record_subscription(&mut bob, 500_000_001).unwrap_err(),
+            RenewraError::FundCapExceeded.into()
+        );
+        governance.record_subscription(&mut bob, 500_000_000).unwrap();
+        
+        // Rejected subscriptions are not counted
+        assert_eq!(alice.total_deposited, 1_500_000_000);
+        assert_eq!(bob.total_deposited, 500_000_000);
+        assert_eq!(governance.total_deposits, 2_000_000_000);
+    }
+    
+    #[test]
+    fn zero_caps_mean_no_limit() {
+        let mut governance = governance();
+        let mut alice = position();
+        
+        governance.record_subscription(&mut alice, 1).unwrap();
+        governance.record_subscription(&mut alice, u64::MAX / 2).unwrap();
+        assert_eq!(governance.total_deposits, u64::MAX / 2 + 1);
+        
+        // Totals still cannot overflow
+        assert_eq!(
+            governance.record_subscription(&mut alice, u64::MAX).unwrap_err(),
+            RenewraError::ArithmeticOverflow.into()
+        );
+    }
+    
+    fn attestation(expires_at: i64, revoked: bool) -> InvestorAttestation {
+        InvestorAttestation {
+            investor: Pubkey::new_unique(),
+            jurisdiction: *b"US",
+            issued_at: 1_000,
+            expires_at,
+            revoked,
+            issued_by: Pubkey::new_unique(),
+            bump: 255,
+        }
+    }
+    
+    #[test]
+    fn kyc_requires_an_unexpired_unrevoked_attestation() {
+        let mut governance = governance();
+        let valid = attestation(2_000, false);
+        let revoked = attestation(2_000, true);
+        
+        // Not enforced while require_kyc is off
+        assert!(governance.check_kyc(None, 5_000).is_ok());
+        assert!(governance.check_kyc(Some(&revoked), 5_000).is_ok());
+        
+        governance.require_kyc = true;
+        assert_eq!(
+            governance.check_kyc(None, 1_500).unwrap_err(),
+            RenewraError::KycRequired.into()
+        );
+        assert!(governance.check_kyc(Some(&valid), 1_999).is_ok());
+        
+        // Expires at expires_at itself
+        assert_eq!(
+            governance.check_kyc(Some(&valid), 2_000).unwrap_err(),
+            RenewraError::AttestationExpired.into()
+        );
+        assert_eq!(
+            governance.check_kyc(Some(&revoked), 1_500).unwrap_err(),
+            RenewraError::AttestationRevoked.into()
+        );
+    }
 }
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+
+/// Per-wallet InvestorAttestation PDA issued by governance.compliance_authority.
+/// Required by subscribe and redemption while governance.require_kyc is set.
+#[account]
+#[derive(InitSpace)]
+pub struct InvestorAttestation {
+    /// Investor wallet the attestation covers
+    pub investor: Pubkey,
+    
+    /// ISO 3166-1 alpha-2 jurisdiction code (e.g. b"US")
+    pub jurisdiction: [u8; 2],
+    
+    /// Unix timestamp when the attestation was (re)issued
+    pub issued_at: i64,
+    
+    /// Unix timestamp after which the attestation is no longer valid
+    pub expires_at: i64,
+    
+    /// Set by revoke_attestation; cleared when re-issued
+    pub revoked: bool,
+    
+    /// Compliance authority that issued the attestation
+    pub issued_by: Pubkey,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl InvestorAttestation {
+    /// PDA seed prefix for investor_attestation accounts (followed by investor pubkey)
+    pub const SEED: &'static [u8] = b"investor_attestation";
+    
+    /// Space: 8 (discriminator) + 32 + 2 + 8 + 8 + 1 + 32 + 1 = 92 bytes
+    pub const SPACE: usize = 8 + 32 + 2 + 8 + 8 + 1 + 32 + 1;
+    
+    /// Fail unless the attestation is unrevoked and unexpired at `now`
+    pub fn validate(&self, now: i64) -> Result<()> {
+        require!(!self.revoked, RenewraError::AttestationRevoked);
+        require!(now < self.expires_at, RenewraError::AttestationExpired);
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Per-investor InvestorPosition PDA tracking lifetime subscriptions for the
+/// governance per-investor deposit cap.
+#[account]
+#[derive(InitSpace)]
+pub struct InvestorPosition {
+    /// Investor wallet this position belongs to
+    pub owner: Pubkey,
+    
+    /// Lifetime USDC subscribed (gross, before mint fee)
+    pub total_deposited: u64,
+    
+    /// Unix timestamp of the most recent subscription
+    pub last_subscribed_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl InvestorPosition {
+    /// PDA seed prefix for investor_position accounts (followed by owner pubkey)
+    pub const SEED: &'static [u8] = b"investor_position";
+    
+    /// Space: 8 (discriminator) + 32 + 8 + 8 + 1 = 57 bytes
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 1;
+}
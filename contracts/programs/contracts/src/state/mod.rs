@@ -1,7 +1,19 @@
+pub mod fee_vault;
 pub mod governance;
+pub mod investor_attestation;
+pub mod investor_position;
+pub mod nav_history;
 pub mod nav_oracle;
+pub mod oracle_config;
 pub mod redemption_queue;
+pub mod yield_state;
 
+pub use fee_vault::*;
 pub use governance::*;
+pub use investor_attestation::*;
+pub use investor_position::*;
+pub use nav_history::*;
 pub use nav_oracle::*;
+pub use oracle_config::*;
 pub use redemption_queue::*;
+pub use yield_state::*;
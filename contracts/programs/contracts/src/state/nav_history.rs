@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+/// Number of NAV updates retained in the history ring
+pub const NAV_HISTORY_CAPACITY: usize = 64;
+
+/// Single NAV history entry
+#[zero_copy]
+pub struct NavHistoryEntry {
+    /// NAV per token (NAV_DECIMALS fixed point) at this update
+    pub nav: u64,
+    
+    /// Unix timestamp of the update
+    pub timestamp: i64,
+    
+    /// Oracle signer that finalized the update (fund admin for reinvest uplifts)
+    pub oracle: Pubkey,
+    
+    /// NavOracle.cumulative_nav_seconds at `timestamp` (TWAP checkpoint)
+    pub cumulative_nav_seconds: u128,
+}
+
+/// NavHistory PDA keeps a fixed-size ring of recent NAV updates, appended by submit_nav
+/// and by distribute_yield when it reinvests.
+/// Zero-copy so other programs and clients can read entries at fixed offsets.
+#[account(zero_copy)]
+pub struct NavHistory {
+    /// Ring buffer of entries (only `count` are valid, oldest overwritten first)
+    pub entries: [NavHistoryEntry; NAV_HISTORY_CAPACITY],
+    
+    /// Index of the slot the next entry will be written to
+    pub head: u64,
+    
+    /// Number of valid entries (saturates at NAV_HISTORY_CAPACITY)
+    pub count: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    
+    /// Padding to keep the account layout 16-byte aligned
+    pub _padding: [u8; 15],
+}
+
+impl NavHistory {
+    /// PDA seed for nav_history account
+    pub const SEED: &'static [u8] = b"nav_history";
+    
+    /// Space: 8 (discriminator) + (8 + 8 + 32 + 16) * 64 + 8 + 8 + 1 + 15 = 4136 bytes
+    pub const SPACE: usize = 8 + (8 + 8 + 32 + 16) * NAV_HISTORY_CAPACITY + 8 + 8 + 1 + 15;
+    
+    /// Append an entry, overwriting the oldest one once the ring is full
+    pub fn push(&mut self, nav: u64, timestamp: i64, oracle: Pubkey, cumulative_nav_seconds: u128) {
+        let head = self.head as usize % NAV_HISTORY_CAPACITY;
+        self.entries[head] = NavHistoryEntry {
+            nav,
+            timestamp,
+            oracle,
+            cumulative_nav_seconds,
+        };
+        self.head = ((head + 1) % NAV_HISTORY_CAPACITY) as u64;
+        self.count = (self.count + 1).min(NAV_HISTORY_CAPACITY as u64);
+    }
+    
+    /// Entry `age` updates ago (0 = most recent), if still retained
+    pub fn recent(&self, age: usize) -> Option<&NavHistoryEntry> {
+        if age >= self.count as usize {
+            return None;
+        }
+        
+        let index = (self.head as usize + NAV_HISTORY_CAPACITY - 1 - age) % NAV_HISTORY_CAPACITY;
+        Some(&self.entries[index])
+    }
+}
@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::RenewraError;
+use crate::state::{Governance, NavHistory, PricingMode};
+
 /// NavOracle PDA stores the latest NAV (Net Asset Value) per token.
 /// Updated hourly by the oracle service.
 #[account]
 #[derive(InitSpace)]
 pub struct NavOracle {
-    /// Current NAV per token in cents (e.g., 967 = $9.67)
+    /// Current NAV per token, fixed-point with NAV_DECIMALS (e.g., 9_670_000 = $9.67)
     pub latest_nav: u64,
     
     /// Previous NAV for comparison and change tracking
@@ -16,12 +19,270 @@ pub struct NavOracle {
     
     /// Bump seed for PDA derivation
     pub bump: u8,
+    
+    // Fields below were appended after the original 33-byte layout
+    
+    /// Running sum of NAV * seconds up to `cumulative_timestamp` (Uniswap-style TWAP accumulator)
+    pub cumulative_nav_seconds: u128,
+    
+    /// Unix timestamp the accumulator was last rolled forward to (oracle update or reinvest)
+    pub cumulative_timestamp: i64,
 }
 
 impl NavOracle {
     /// PDA seed for nav_oracle account
     pub const SEED: &'static [u8] = b"nav_oracle";
     
-    /// Space: 8 (discriminator) + 8 + 8 + 8 + 1 = 33 bytes
-    pub const SPACE: usize = 8 + 8 + 8 + 8 + 1;
+    /// Decimals of the fixed-point NAV (matches USDC and REI)
+    pub const NAV_DECIMALS: u8 = 6;
+    
+    /// Fixed-point scale of the NAV (10^NAV_DECIMALS)
+    pub const NAV_SCALE: u64 = 1_000_000;
+    
+    /// Multiplier from legacy whole-cent NAVs to NAV_DECIMALS fixed point
+    pub const LEGACY_CENTS_TO_NAV: u64 = Self::NAV_SCALE / 100;
+    
+    /// Space: 8 (discriminator) + 8 + 8 + 8 + 1 + 16 + 8 = 57 bytes
+    pub const SPACE: usize = 8 + 8 + 8 + 8 + 1 + 16 + 8;
+    
+    /// REI (6 decimals) bought by `usdc` (6 decimals) at `nav`, rounded down in favour of the fund
+    pub fn usdc_to_tokens(usdc: u64, nav: u64) -> Result<u64> {
+        let tokens = (usdc as u128)
+            .checked_mul(Self::NAV_SCALE as u128)
+            .ok_or(RenewraError::ArithmeticOverflow)?
+            .checked_div(nav as u128)
+            .ok_or(RenewraError::InvalidNavPrice)?;
+        u64::try_from(tokens).map_err(|_| RenewraError::ArithmeticOverflow.into())
+    }
+    
+    /// USDC (6 decimals) owed for `tokens` (6 decimals) at `nav`, rounded down in favour of the fund
+    pub fn tokens_to_usdc(tokens: u64, nav: u64) -> Result<u64> {
+        let usdc = (tokens as u128)
+            .checked_mul(nav as u128)
+            .ok_or(RenewraError::ArithmeticOverflow)?
+            / Self::NAV_SCALE as u128;
+        u64::try_from(usdc).map_err(|_| RenewraError::ArithmeticOverflow.into())
+    }
+    
+    /// Accumulator value extrapolated to `now` at the latest NAV
+    pub fn cumulative_at(&self, now: i64) -> u128 {
+        let elapsed = now.saturating_sub(self.cumulative_timestamp).max(0) as u128;
+        self.cumulative_nav_seconds
+            .saturating_add((self.latest_nav as u128).saturating_mul(elapsed))
+    }
+    
+    /// Roll the accumulator forward to `now` and make `new_nav` the latest NAV
+    pub fn record(&mut self, new_nav: u64, now: i64) {
+        self.cumulative_nav_seconds = self.cumulative_at(now);
+        self.cumulative_timestamp = now;
+        self.previous_nav = self.latest_nav;
+        self.latest_nav = new_nav;
+        self.timestamp = now;
+    }
+    
+    /// Raise the latest NAV to `new_nav` at `now` without an oracle update (reinvested
+    /// yield). The accumulator is rolled forward to `now` at the old NAV first, while
+    /// `timestamp` keeps marking the last oracle submission so staleness checks still
+    /// apply. Callers must push a NAV history checkpoint afterwards so `twap` sees the step.
+    pub fn apply_reinvest(&mut self, new_nav: u64, now: i64) -> Result<()> {
+        require!(new_nav >= self.latest_nav, RenewraError::InvalidNavPrice);
+        
+        self.cumulative_nav_seconds = self.cumulative_at(now);
+        self.cumulative_timestamp = now;
+        self.previous_nav = self.latest_nav;
+        self.latest_nav = new_nav;
+        
+        Ok(())
+    }
+    
+    /// Time-weighted average NAV over the last `window_secs` seconds, using NAV history
+    /// checkpoints. Falls back to the longest window the history still covers and
+    /// fails with InsufficientNavHistory when no checkpoint has been recorded yet.
+    pub fn twap(&self, history: &NavHistory, now: i64, window_secs: i64) -> Result<u64> {
+        let window_start = now.saturating_sub(window_secs);
+        
+        // Newest checkpoint at or before the window start, or the oldest one retained
+        let mut checkpoint = None;
+        for age in 0..history.count as usize {
+            let Some(entry) = history.recent(age) else {
+                break;
+            };
+            checkpoint = Some(entry);
+            if entry.timestamp <= window_start {
+                break;
+            }
+        }
+        let checkpoint = checkpoint.ok_or(RenewraError::InsufficientNavHistory)?;
+        
+        let start = window_start.max(checkpoint.timestamp);
+        if now <= start {
+            return Ok(self.latest_nav);
+        }
+        
+        // Accumulator at `start`: checkpoint value plus its NAV held since then
+        let cumulative_start = (checkpoint.nav as u128)
+            .checked_mul(start.saturating_sub(checkpoint.timestamp) as u128)
+            .and_then(|held| checkpoint.cumulative_nav_seconds.checked_add(held))
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        let cumulative_now = self.cumulative_at(now);
+        
+        let twap = cumulative_now
+            .checked_sub(cumulative_start)
+            .ok_or(RenewraError::ArithmeticOverflow)?
+            / now.saturating_sub(start) as u128;
+        u64::try_from(twap).map_err(|_| RenewraError::ArithmeticOverflow.into())
+    }
+    
+    /// NAV used to price subscriptions and redemptions under the governance pricing mode
+    pub fn pricing_nav(&self, governance: &Governance, history: &NavHistory, now: i64) -> Result<u64> {
+        let nav = match governance.pricing_mode {
+            PricingMode::Spot => self.latest_nav,
+            PricingMode::Twap => self.twap(history, now, governance.twap_window_secs)?,
+        };
+        require!(nav > 0, RenewraError::InvalidNavPrice);
+        Ok(nav)
+    }
+    
+    /// Whether the latest NAV is older than `max_age_secs` at time `now`
+    pub fn is_stale(&self, now: i64, max_age_secs: i64) -> bool {
+        now.saturating_sub(self.timestamp) > max_age_secs
+    }
+    
+    /// Absolute change from latest_nav to `new_nav` in basis points (u64::MAX on overflow)
+    pub fn deviation_bps(&self, new_nav: u64) -> u64 {
+        if self.latest_nav == 0 {
+            return 0;
+        }
+        
+        let diff = self.latest_nav.abs_diff(new_nav) as u128;
+        diff.checked_mul(10_000)
+            .map(|scaled| scaled / self.latest_nav as u128)
+            .and_then(|bps| u64::try_from(bps).ok())
+            .unwrap_or(u64::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const ORACLE: Pubkey = Pubkey::new_from_array([7; 32]);
+    
+    fn oracle(nav: u64, timestamp: i64) -> NavOracle {
+        NavOracle {
+            latest_nav: nav,
+            previous_nav: nav,
+            timestamp,
+            bump: 255,
+            cumulative_nav_seconds: 0,
+            cumulative_timestamp: timestamp,
+        }
+    }
+    
+    /// History seeded with the oracle's current state, as initialize_fund does
+    fn history(oracle: &NavOracle) -> NavHistory {
+        let mut history: NavHistory = bytemuck::Zeroable::zeroed();
+        history.push(
+            oracle.latest_nav,
+            oracle.timestamp,
+            ORACLE,
+            oracle.cumulative_nav_seconds,
+        );
+        history
+    }
+    
+    /// Reinvest the way distribute_yield does: raise the NAV, then checkpoint it
+    fn reinvest(oracle: &mut NavOracle, history: &mut NavHistory, new_nav: u64, now: i64) {
+        oracle.apply_reinvest(new_nav, now).unwrap();
+        history.push(oracle.latest_nav, now, ORACLE, oracle.cumulative_nav_seconds);
+    }
+    
+    #[test]
+    fn reinvest_keeps_oracle_timestamp_and_twap_consistent() {
+        let mut oracle = oracle(50_000_000, 1_000);
+        let before = oracle.cumulative_at(1_100);
+        
+        oracle.apply_reinvest(50_500_000, 1_100).unwrap();
+        
+        assert_eq!(oracle.timestamp, 1_000);
+        assert!(oracle.is_stale(1_000 + 3_601, 3_600));
+        assert_eq!(oracle.previous_nav, 50_000_000);
+        assert_eq!(oracle.latest_nav, 50_500_000);
+        
+        // Old NAV counted up to the reinvest, new NAV afterwards
+        assert_eq!(oracle.cumulative_at(1_100), before);
+        assert_eq!(oracle.cumulative_at(1_200), before + 50_500_000 * 100);
+        
+        // NAV can only be raised this way
+        assert!(oracle.apply_reinvest(50_000_000, 1_200).is_err());
+    }
+    
+    #[test]
+    fn twap_never_exceeds_the_nav_held_after_reinvest() {
+        let t = 1_700_000_000;
+        let mut oracle = oracle(50_000_000, t);
+        let mut history = history(&oracle);
+        
+        reinvest(&mut oracle, &mut history, 52_000_000, t + 600);
+        
+        // Window entirely after the reinvest sees only the raised NAV
+        assert_eq!(oracle.twap(&history, t + 3_000, 1_800).unwrap(), 52_000_000);
+        
+        // Window spanning it weights each NAV by the time it was held:
+        // (50.00 * 600 + 52.00 * 2400) / 3000 = 51.60
+        assert_eq!(oracle.twap(&history, t + 3_000, 3_000).unwrap(), 51_600_000);
+    }
+    
+    #[test]
+    fn reinvest_on_a_fresh_oracle() {
+        let t = 1_700_000_000;
+        let mut oracle = oracle(50_000_000, t);
+        let mut history = history(&oracle);
+        
+        // Accumulator is still zero; reinvest before any oracle update
+        reinvest(&mut oracle, &mut history, 50_250_000, t + 60);
+        
+        assert_eq!(oracle.cumulative_nav_seconds, 50_000_000 * 60);
+        assert_eq!(oracle.cumulative_timestamp, t + 60);
+        assert_eq!(oracle.timestamp, t);
+        assert_eq!(oracle.twap(&history, t + 120, 60).unwrap(), 50_250_000);
+        assert_eq!(oracle.twap(&history, t + 120, 120).unwrap(), 50_125_000);
+        
+        // A later oracle update rolls forward from the reinvest, not the old timestamp
+        oracle.record(50_300_000, t + 120);
+        assert_eq!(oracle.cumulative_nav_seconds, 50_000_000 * 60 + 50_250_000 * 60);
+    }
+    
+    #[test]
+    fn conversions_round_down_in_favour_of_the_fund() {
+        // 100 USDC at $3.00 buys 33.333333 REI, not 33.333334
+        assert_eq!(NavOracle::usdc_to_tokens(100_000_000, 3_000_000).unwrap(), 33_333_333);
+        
+        // Those tokens redeem for a hair under the 100 USDC paid
+        assert_eq!(NavOracle::tokens_to_usdc(33_333_333, 3_000_000).unwrap(), 99_999_999);
+        
+        // Sub-unit amounts round to zero rather than up
+        assert_eq!(NavOracle::usdc_to_tokens(2, 3_000_000).unwrap(), 0);
+        assert_eq!(NavOracle::tokens_to_usdc(1, 999_999).unwrap(), 0);
+        
+        // Exact at a whole NAV
+        assert_eq!(NavOracle::usdc_to_tokens(100_000_000, 10_000_000).unwrap(), 10_000_000);
+        assert_eq!(NavOracle::tokens_to_usdc(10_000_000, 10_000_000).unwrap(), 100_000_000);
+    }
+    
+    #[test]
+    fn conversions_reject_zero_nav_and_overflow() {
+        assert_eq!(
+            NavOracle::usdc_to_tokens(100_000_000, 0).unwrap_err(),
+            RenewraError::InvalidNavPrice.into()
+        );
+        assert_eq!(
+            NavOracle::usdc_to_tokens(u64::MAX, 1).unwrap_err(),
+            RenewraError::ArithmeticOverflow.into()
+        );
+        assert_eq!(
+            NavOracle::tokens_to_usdc(u64::MAX, 2 * NavOracle::NAV_SCALE).unwrap_err(),
+            RenewraError::ArithmeticOverflow.into()
+        );
+    }
 }
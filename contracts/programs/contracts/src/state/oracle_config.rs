@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of oracle keys in the quorum set
+pub const MAX_ORACLES: usize = 5;
+
+/// Single oracle submission within a pending NAV round
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct NavSubmission {
+    /// Oracle that submitted the value
+    pub oracle: Pubkey,
+    
+    /// Submitted NAV per token (NAV_DECIMALS fixed point)
+    pub nav: u64,
+}
+
+/// OracleConfig PDA holds the M-of-N oracle set and the pending NAV round.
+/// With an empty set the fund falls back to the single governance.oracle_signer.
+#[account]
+#[derive(InitSpace)]
+pub struct OracleConfig {
+    /// Authorized oracle keys (only the first `oracle_count` entries are valid)
+    pub oracles: [Pubkey; MAX_ORACLES],
+    
+    /// Number of configured oracle keys (N); 0 = single governance.oracle_signer
+    pub oracle_count: u8,
+    
+    /// Number of agreeing submissions required to finalize a round (M)
+    pub quorum: u8,
+    
+    /// Max distance from the round median for a submission to agree (basis points)
+    pub tolerance_bps: u16,
+    
+    /// Seconds after which an unfinished round is discarded
+    pub round_timeout_secs: i64,
+    
+    /// Identifier of the pending round (increments every time a round closes)
+    pub round_id: u64,
+    
+    /// Unix timestamp of the first submission in the pending round
+    pub round_opened_at: i64,
+    
+    /// Submissions received in the pending round
+    pub submissions: [NavSubmission; MAX_ORACLES],
+    
+    /// Number of valid entries in `submissions`
+    pub submission_count: u8,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl OracleConfig {
+    /// PDA seed for oracle_config account
+    pub const SEED: &'static [u8] = b"oracle_config";
+    
+    /// Default agreement tolerance between oracle submissions (1%)
+    pub const DEFAULT_TOLERANCE_BPS: u16 = 100;
+    
+    /// Default lifetime of an unfinished round (15 minutes)
+    pub const DEFAULT_ROUND_TIMEOUT_SECS: i64 = 15 * 60;
+    
+    /// Space: 8 (discriminator) + 32 * 5 + 1 + 1 + 2 + 8 + 8 + 8 + (32 + 8) * 5 + 1 + 1 = 398 bytes
+    pub const SPACE: usize =
+        8 + 32 * MAX_ORACLES + 1 + 1 + 2 + 8 + 8 + 8 + (32 + 8) * MAX_ORACLES + 1 + 1;
+    
+    /// Whether the quorum set is in use (otherwise governance.oracle_signer applies)
+    pub fn has_oracle_set(&self) -> bool {
+        self.oracle_count > 0
+    }
+    
+    /// Whether `key` is a member of the configured oracle set
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.oracles[..self.oracle_count as usize].contains(key)
+    }
+    
+    /// Number of agreeing submissions needed to finalize (1 in single-signer mode)
+    pub fn effective_quorum(&self) -> u8 {
+        if self.has_oracle_set() {
+            self.quorum
+        } else {
+            1
+        }
+    }
+    
+    /// Number of oracles that can take part in a round
+    pub fn max_participants(&self) -> usize {
+        if self.has_oracle_set() {
+            self.oracle_count as usize
+        } else {
+            1
+        }
+    }
+    
+    /// Submissions received in the pending round
+    pub fn pending(&self) -> &[NavSubmission] {
+        &self.submissions[..self.submission_count as usize]
+    }
+    
+    /// Whether `oracle` has already submitted in the pending round
+    pub fn has_submitted(&self, oracle: &Pubkey) -> bool {
+        self.pending().iter().any(|s| s.oracle == *oracle)
+    }
+    
+    /// Discard the pending round and move on to the next round id
+    pub fn reset_round(&mut self) {
+        self.submissions = [NavSubmission::default(); MAX_ORACLES];
+        self.submission_count = 0;
+        self.round_opened_at = 0;
+        self.round_id = self.round_id.saturating_add(1);
+    }
+    
+    /// If at least `effective_quorum` submissions lie within `tolerance_bps` of the
+    /// round median, returns the median of those agreeing submissions.
+    pub fn agreed_nav(&self) -> Option<u64> {
+        let pending = self.pending();
+        if pending.is_empty() {
+            return None;
+        }
+        
+        let mut navs: Vec<u64> = pending.iter().map(|s| s.nav).collect();
+        let round_median = median(&mut navs);
+        
+        let agreeing: Vec<&NavSubmission> = pending
+            .iter()
+            .filter(|s| {
+                let diff = s.nav.abs_diff(round_median) as u128;
+                diff * 10_000 <= round_median as u128 * self.tolerance_bps as u128
+            })
+            .collect();
+        if agreeing.len() < self.effective_quorum() as usize {
+            return None;
+        }
+        
+        let mut agreeing_navs: Vec<u64> = agreeing.iter().map(|s| s.nav).collect();
+        Some(median(&mut agreeing_navs))
+    }
+}
+
+/// Median of a non-empty slice (mean of the two middle values, rounded down, for even lengths)
+fn median(values: &mut [u64]) -> u64 {
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len() % 2 == 1 {
+        values[mid]
+    } else {
+        ((values[mid - 1] as u128 + values[mid] as u128) / 2) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn oracle_config(quorum: u8, tolerance_bps: u16, navs: &[u64]) -> OracleConfig {
+        let mut oracles = [Pubkey::default(); MAX_ORACLES];
+        let mut submissions = [NavSubmission::default(); MAX_ORACLES];
+        for (i, nav) in navs.iter().enumerate() {
+            oracles[i] = Pubkey::new_unique();
+            submissions[i] = NavSubmission {
+                oracle: oracles[i],
+                nav: *nav,
+            };
+        }
+        
+        OracleConfig {
+            oracles,
+            oracle_count: MAX_ORACLES as u8,
+            quorum,
+            tolerance_bps,
+            round_timeout_secs: OracleConfig::DEFAULT_ROUND_TIMEOUT_SECS,
+            round_id: 1,
+            round_opened_at: 1_700_000_000,
+            submissions,
+            submission_count: navs.len() as u8,
+            bump: 255,
+        }
+    }
+    
+    #[test]
+    fn agreed_nav_is_the_median_of_agreeing_submissions() {
+        // Odd count: middle value
+        let config = oracle_config(3, 100, &[10_050_000, 10_000_000, 10_020_000]);
+        assert_eq!(config.agreed_nav(), Some(10_020_000));
+        
+        // Even count: mean of the two middle values, rounded down
+        let config = oracle_config(4, 100, &[10_000_000, 10_000_001, 10_000_002, 10_000_004]);
+        assert_eq!(config.agreed_nav(), Some(10_000_001));
+    }
+    
+    #[test]
+    fn agreed_nav_drops_submissions_outside_tolerance() {
+        // 1% of the 10.00 median is 0.10: 10.10 agrees, 10.100001 does not
+        let config = oracle_config(3, 100, &[10_100_000, 9_990_000, 10_000_000, 10_100_001, 10_000_000]);
+        assert_eq!(config.agreed_nav(), Some(10_000_000));
+        
+        // The outlier does not move the result
+        let config = oracle_config(2, 100, &[10_000_000, 10_020_000, 20_000_000]);
+        assert_eq!(config.agreed_nav(), Some(10_010_000));
+    }
+    
+    #[test]
+    fn agreed_nav_requires_quorum() {
+        let config = oracle_config(3, 100, &[]);
+        assert_eq!(config.agreed_nav(), None);
+        
+        // Only two submissions so far
+        let config = oracle_config(3, 100, &[10_000_000, 10_000_000]);
+        assert_eq!(config.agreed_nav(), None);
+        
+        // Three submitted but one disagrees
+        let config = oracle_config(3, 100, &[10_000_000, 10_010_000, 12_000_000]);
+        assert_eq!(config.agreed_nav(), None);
+        
+        // Single-signer mode needs just one submission
+        let mut config = oracle_config(3, 100, &[10_000_000]);
+        config.oracle_count = 0;
+        assert_eq!(config.agreed_nav(), Some(10_000_000));
+    }
+}
@@ -9,11 +9,18 @@ pub enum RedemptionStatus {
     Approved,
     /// Tokens burned, USDC transferred
     Settled,
+    /// Request withdrawn by the requester, escrowed tokens returned
+    Cancelled,
 }
 
-/// Individual redemption request
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+/// Individual redemption request, stored in its own PDA keyed by request_id.
+/// Closed (rent returned to the requester) once settled or cancelled.
+#[account]
+#[derive(InitSpace)]
 pub struct RedemptionRequest {
+    /// Stable identifier assigned from RedemptionQueue.next_request_id
+    pub request_id: u64,
+    
     /// User requesting redemption
     pub requester: Pubkey,
     
@@ -25,21 +32,29 @@ pub struct RedemptionRequest {
     
     /// Current status of the request
     pub status: RedemptionStatus,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
 }
 
 impl RedemptionRequest {
-    /// Space: 32 + 8 + 8 + 1 = 49 bytes (rounded to 48 for alignment)
-    pub const SPACE: usize = 32 + 8 + 8 + 1;
+    /// PDA seed prefix for redemption_request accounts (followed by request_id LE bytes)
+    pub const SEED: &'static [u8] = b"redemption_request";
+    
+    /// Space: 8 (discriminator) + 8 + 32 + 8 + 8 + 1 + 1 = 66 bytes
+    pub const SPACE: usize = 8 + 8 + 32 + 8 + 8 + 1 + 1;
 }
 
-/// RedemptionQueue PDA tracks all pending redemption requests.
-/// Allocates space for up to 100 requests.
+/// RedemptionQueue PDA hands out request ids and tracks open requests.
+/// Requests themselves live in per-request PDAs, so the queue never fills up.
 #[account]
 #[derive(InitSpace)]
 pub struct RedemptionQueue {
-    /// Vector of redemption requests (max 100)
-    #[max_len(100)]
-    pub requests: Vec<RedemptionRequest>,
+    /// Next request_id to assign (monotonically increasing, starts at 1)
+    pub next_request_id: u64,
+    
+    /// Number of requests that are pending or approved
+    pub open_requests: u64,
     
     /// Bump seed for PDA derivation
     pub bump: u8,
@@ -49,9 +64,9 @@ impl RedemptionQueue {
     /// PDA seed for redemption_queue account
     pub const SEED: &'static [u8] = b"redemption_queue";
     
-    /// Maximum number of pending requests
-    pub const MAX_REQUESTS: usize = 100;
+    /// PDA seed for per-user escrow token accounts holding queued REI
+    pub const ESCROW_SEED: &'static [u8] = b"redeem_escrow";
     
-    /// Space: 8 (discriminator) + 4 (vec len) + (49 * 100) + 1 = 4913 bytes
-    pub const SPACE: usize = 8 + 4 + (RedemptionRequest::SPACE * Self::MAX_REQUESTS) + 1;
+    /// Space: 8 (discriminator) + 8 + 8 + 1 = 25 bytes
+    pub const SPACE: usize = 8 + 8 + 8 + 1;
 }
@@ -0,0 +1,256 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RenewraError;
+
+/// How distribute_yield hands yield to holders
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum YieldMode {
+    /// Raise the reward index; holders claim USDC through claim_yield
+    Cash,
+    /// Keep USDC in the treasury and raise the NAV by yield / supply
+    Reinvest,
+}
+
+/// YieldState PDA holds the fund-wide reward-per-token accumulator.
+/// Each distribution raises the index by yield_amount / earning_supply; holders
+/// claim the difference between the current index and the one they last saw.
+#[account]
+#[derive(InitSpace)]
+pub struct YieldState {
+    /// Cumulative USDC per REI base unit, scaled by INDEX_SCALE
+    pub reward_per_token: u128,
+    
+    /// Total USDC distributed through distribute_yield
+    pub total_distributed: u64,
+    
+    /// Total USDC paid out through claim_yield
+    pub total_claimed: u64,
+    
+    /// Total USDC reinvested into NAV
+    pub total_reinvested: u64,
+    
+    /// Sum of every UserYield.recorded_balance: the REI that can claim cash yield.
+    /// Escrowed REI and holders without a checkpoint (e.g. the fee recipient) are excluded.
+    pub earning_supply: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl YieldState {
+    /// PDA seed for yield_state account
+    pub const SEED: &'static [u8] = b"yield_state";
+    
+    /// Fixed-point scale of reward_per_token (1e12)
+    pub const INDEX_SCALE: u128 = 1_000_000_000_000;
+    
+    /// Space: 8 (discriminator) + 16 + 8 + 8 + 8 + 8 + 1 = 57 bytes
+    pub const SPACE: usize = 8 + 16 + 8 + 8 + 8 + 8 + 1;
+    
+    /// USDC distributed to holders but not yet claimed
+    pub fn unclaimed(&self) -> u64 {
+        self.total_distributed.saturating_sub(self.total_claimed)
+    }
+    
+    /// Raise the index by yield_amount spread over earning_supply, rounded down.
+    /// Returns the index increment.
+    pub fn distribute(&mut self, yield_amount: u64) -> Result<u128> {
+        require!(self.earning_supply > 0, RenewraError::InvalidAmount);
+        
+        let increment = (yield_amount as u128)
+            .checked_mul(Self::INDEX_SCALE)
+            .ok_or(RenewraError::ArithmeticOverflow)?
+            / self.earning_supply as u128;
+        
+        self.reward_per_token = self
+            .reward_per_token
+            .checked_add(increment)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        self.total_distributed = self
+            .total_distributed
+            .checked_add(yield_amount)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        
+        Ok(increment)
+    }
+}
+
+/// Per-holder UserYield PDA recording the index the holder last saw and the
+/// REI balance that index applies to.
+#[account]
+#[derive(InitSpace)]
+pub struct UserYield {
+    /// REI holder this record belongs to
+    pub owner: Pubkey,
+    
+    /// YieldState.reward_per_token at the last checkpoint
+    pub reward_per_token_paid: u128,
+    
+    /// USDC accrued but not yet claimed
+    pub accrued: u64,
+    
+    /// REI wallet balance recorded at the last checkpoint (counted in earning_supply)
+    pub recorded_balance: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl UserYield {
+    /// PDA seed prefix for user_yield accounts (followed by owner pubkey)
+    pub const SEED: &'static [u8] = b"user_yield";
+    
+    /// Space: 8 (discriminator) + 32 + 16 + 8 + 8 + 1 = 73 bytes
+    pub const SPACE: usize = 8 + 32 + 16 + 8 + 8 + 1;
+    
+    /// Set up a freshly created record. Starts at the current index with nothing
+    /// recorded, so earlier distributions cannot be claimed; the first checkpoint
+    /// records the balance.
+    pub fn open_if_new(&mut self, owner: Pubkey, reward_per_token: u128, bump: u8) {
+        if self.owner == Pubkey::default() {
+            self.owner = owner;
+            self.reward_per_token_paid = reward_per_token;
+            self.accrued = 0;
+            self.recorded_balance = 0;
+            self.bump = bump;
+        }
+    }
+    
+    /// Accrue rewards since the last checkpoint and move to the current index.
+    /// Earns on min(recorded, current) balance so tokens received outside the
+    /// program never earn for periods before they were checkpointed.
+    pub fn checkpoint(&mut self, state: &mut YieldState, current_balance: u64) -> Result<()> {
+        let reward_per_token = state.reward_per_token;
+        let earning_balance = self.recorded_balance.min(current_balance);
+        let delta = reward_per_token.saturating_sub(self.reward_per_token_paid);
+        
+        let earned = (earning_balance as u128)
+            .checked_mul(delta)
+            .ok_or(RenewraError::ArithmeticOverflow)?
+            .checked_div(YieldState::INDEX_SCALE)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        
+        self.accrued = self
+            .accrued
+            .checked_add(u64::try_from(earned).map_err(|_| RenewraError::ArithmeticOverflow)?)
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        self.reward_per_token_paid = reward_per_token;
+        self.record_balance(state, current_balance)
+    }
+    
+    /// Checkpoint around an REI balance change (transfer, mint or escrow move):
+    /// accrue on the balance held before it, then record the balance held after it.
+    pub fn checkpoint_transfer(
+        &mut self,
+        state: &mut YieldState,
+        balance_before: u64,
+        balance_after: u64,
+    ) -> Result<()> {
+        self.checkpoint(state, balance_before)?;
+        self.record_balance(state, balance_after)
+    }
+    
+    /// Replace the recorded balance, keeping the fund-wide earning_supply in step
+    fn record_balance(&mut self, state: &mut YieldState, balance: u64) -> Result<()> {
+        state.earning_supply = state
+            .earning_supply
+            .checked_sub(self.recorded_balance)
+            .and_then(|others| others.checked_add(balance))
+            .ok_or(RenewraError::ArithmeticOverflow)?;
+        self.recorded_balance = balance;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const SCALE: u128 = YieldState::INDEX_SCALE;
+    
+    fn yield_state() -> YieldState {
+        YieldState {
+            reward_per_token: 0,
+            total_distributed: 0,
+            total_claimed: 0,
+            total_reinvested: 0,
+            earning_supply: 0,
+            bump: 255,
+        }
+    }
+    
+    /// Holder that subscribed for `balance` REI
+    fn holder(state: &mut YieldState, balance: u64) -> UserYield {
+        let mut user_yield = UserYield {
+            owner: Pubkey::default(),
+            reward_per_token_paid: 0,
+            accrued: 0,
+            recorded_balance: 0,
+            bump: 0,
+        };
+        user_yield.open_if_new(Pubkey::new_unique(), state.reward_per_token, 255);
+        user_yield.checkpoint_transfer(state, 0, balance).unwrap();
+        user_yield
+    }
+    
+    fn claim(user_yield: &mut UserYield, state: &mut YieldState, balance: u64) -> u64 {
+        user_yield.checkpoint(state, balance).unwrap();
+        let amount = std::mem::take(&mut user_yield.accrued);
+        state.total_claimed += amount;
+        amount
+    }
+    
+    #[test]
+    fn transfers_between_holders_never_pay_yield_twice() {
+        let mut state = yield_state();
+        let (mut alice, mut bob) = (holder(&mut state, 100), holder(&mut state, 100));
+        assert_eq!(state.earning_supply, 200);
+        
+        // 200 USDC over 200 REI: 1 USDC per REI, held by Alice and Bob
+        state.distribute(200).unwrap();
+        assert_eq!(state.reward_per_token, SCALE);
+        
+        // Alice sends everything to Bob; the hook checkpoints both sides
+        alice.checkpoint_transfer(&mut state, 100, 0).unwrap();
+        bob.checkpoint_transfer(&mut state, 100, 200).unwrap();
+        
+        // Second distribution is earned by Bob alone
+        state.distribute(200).unwrap();
+        
+        // Bob sends the tokens back before anyone claims
+        bob.checkpoint_transfer(&mut state, 200, 0).unwrap();
+        alice.checkpoint_transfer(&mut state, 0, 200).unwrap();
+        
+        assert_eq!(claim(&mut alice, &mut state, 200), 100);
+        assert_eq!(claim(&mut bob, &mut state, 0), 300);
+        assert_eq!(state.total_claimed, state.total_distributed);
+        
+        // Nothing left for either side
+        assert_eq!(claim(&mut alice, &mut state, 200), 0);
+        assert_eq!(claim(&mut bob, &mut state, 0), 0);
+        assert_eq!(state.earning_supply, 200);
+    }
+    
+    #[test]
+    fn escrowed_and_unrecorded_rei_do_not_dilute_cash_yield() {
+        let mut state = yield_state();
+        let mut alice = holder(&mut state, 100);
+        let mut bob = holder(&mut state, 100);
+        
+        // Bob escrows everything for redemption; the management fee mints 50 REI
+        // to a recipient without a checkpoint. Mint supply is 250, earning supply 100.
+        bob.checkpoint_transfer(&mut state, 100, 0).unwrap();
+        assert_eq!(state.earning_supply, 100);
+        
+        state.distribute(100).unwrap();
+        assert_eq!(claim(&mut alice, &mut state, 100), 100);
+        assert_eq!(claim(&mut bob, &mut state, 0), 0);
+        
+        // Nothing stays earmarked for holders who can never claim it
+        assert_eq!(state.unclaimed(), 0);
+        
+        // Without any checkpointed holder there is nobody to distribute to
+        alice.checkpoint_transfer(&mut state, 100, 0).unwrap();
+        assert!(state.distribute(100).is_err());
+    }
+}
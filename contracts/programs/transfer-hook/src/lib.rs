@@ -0,0 +1,303 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions},
+    state::Account as SplTokenAccount,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use contracts::cpi::accounts::CheckpointTransfer;
+use contracts::instructions::CHECKPOINT_AUTHORITY_SEED;
+use contracts::state::{Governance, InvestorAttestation, UserYield, YieldState};
+
+declare_id!("3TtFMPAxzR1DDC4vymM9xfhmAh5Ktp8fs4fhGpd4hZFo");
+
+/// PDA seed for the ExtraAccountMetaList (fixed by the transfer hook interface)
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// REI mint PDA seed in the Renewra fund program
+pub const REIT_MINT_SEED: &[u8] = b"reit_mint";
+
+#[program]
+pub mod transfer_hook {
+    use super::*;
+
+    /// Create the ExtraAccountMetaList so Token-2022 passes the fund governance, the
+    /// receiver's attestation and both holders' yield checkpoints into every REI transfer
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        let metas = extra_account_metas()?;
+        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+
+        msg!("Transfer hook accounts initialized for mint {}", ctx.accounts.mint.key());
+        Ok(())
+    }
+
+    /// Token-2022 transfer hook: while the fund requires KYC, REI may only move to
+    /// fund-owned accounts or wallets holding a valid investor attestation, except
+    /// when the fund itself returns escrowed REI. Holder to holder transfers also
+    /// checkpoint both sides' yield in the fund program.
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        // Step 1: Only run as part of a Token-2022 transfer
+        assert_is_transferring(&ctx.accounts.source_token)?;
+
+        // Step 2: While KYC is on, the receiver must hold an unrevoked, unexpired
+        // attestation. Transfers into fund-owned accounts (redemption escrow) and out
+        // of them (cancel_redeem refunds) are exempt, so a revoked investor can still
+        // recover escrowed REI.
+        let governance = &ctx.accounts.governance;
+        let fund_key = governance.key();
+        let fund_transfer = ctx.accounts.source_token.owner == fund_key
+            || ctx.accounts.destination_token.owner == fund_key;
+        if governance.require_kyc && !fund_transfer {
+            let attestation_info = ctx.accounts.destination_attestation.to_account_info();
+            require!(
+                attestation_info.owner == &contracts::ID && !attestation_info.data_is_empty(),
+                TransferHookError::ReceiverNotAllowlisted
+            );
+            let attestation =
+                InvestorAttestation::try_deserialize(&mut &attestation_info.try_borrow_data()?[..])?;
+            attestation.validate(Clock::get()?.unix_timestamp)?;
+        }
+
+        // Step 3: Escrow moves are made by the fund program, which checkpoints the
+        // holder itself (and cannot be re-entered from here)
+        if fund_transfer {
+            return Ok(());
+        }
+
+        // Step 4: Checkpoint sender and receiver yield so transferred tokens are
+        // never credited to both
+        let seeds = &[CHECKPOINT_AUTHORITY_SEED, &[ctx.bumps.checkpoint_authority]];
+        let signer_seeds = &[&seeds[..]];
+
+        let checkpoint_ctx = CpiContext::new_with_signer(
+            ctx.accounts.fund_program.to_account_info(),
+            CheckpointTransfer {
+                checkpoint_authority: ctx.accounts.checkpoint_authority.to_account_info(),
+                governance: ctx.accounts.governance.to_account_info(),
+                yield_state: ctx.accounts.yield_state.to_account_info(),
+                source_token: ctx.accounts.source_token.to_account_info(),
+                destination_token: ctx.accounts.destination_token.to_account_info(),
+                source_user_yield: ctx.accounts.source_user_yield.to_account_info(),
+                destination_user_yield: ctx.accounts.destination_user_yield.to_account_info(),
+            },
+            signer_seeds,
+        );
+        contracts::cpi::checkpoint_transfer(checkpoint_ctx, amount)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: ExtraAccountMetaList PDA, written by the handler
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// Fund REI mint (PDA of the Renewra program)
+    #[account(
+        seeds = [REIT_MINT_SEED],
+        bump,
+        seeds::program = contracts::ID
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account order is fixed by the transfer hook interface: source, mint,
+/// destination, owner, meta list, then the extra accounts in meta list order.
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: source owner or delegate, validated by Token-2022
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: ExtraAccountMetaList PDA
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: Renewra fund program (seed program for the PDAs below)
+    #[account(address = contracts::ID)]
+    pub fund_program: UncheckedAccount<'info>,
+
+    /// Fund governance (require_kyc flag)
+    #[account(
+        seeds = [Governance::SEED],
+        bump = governance.bump,
+        seeds::program = contracts::ID
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// CHECK: receiver's InvestorAttestation PDA, may not exist
+    #[account(
+        seeds = [InvestorAttestation::SEED, destination_token.owner.as_ref()],
+        bump,
+        seeds::program = contracts::ID
+    )]
+    pub destination_attestation: UncheckedAccount<'info>,
+
+    /// CHECK: fund-wide reward index and earning supply, validated by the fund program
+    #[account(
+        mut,
+        seeds = [YieldState::SEED],
+        bump,
+        seeds::program = contracts::ID
+    )]
+    pub yield_state: UncheckedAccount<'info>,
+
+    /// CHECK: sender's UserYield PDA, may not exist
+    #[account(
+        mut,
+        seeds = [UserYield::SEED, source_token.owner.as_ref()],
+        bump,
+        seeds::program = contracts::ID
+    )]
+    pub source_user_yield: UncheckedAccount<'info>,
+
+    /// CHECK: receiver's UserYield PDA, may not exist
+    #[account(
+        mut,
+        seeds = [UserYield::SEED, destination_token.owner.as_ref()],
+        bump,
+        seeds::program = contracts::ID
+    )]
+    pub destination_user_yield: UncheckedAccount<'info>,
+
+    /// CHECK: PDA signing the fund program's checkpoint_transfer
+    #[account(
+        seeds = [CHECKPOINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub checkpoint_authority: UncheckedAccount<'info>,
+}
+
+#[error_code]
+pub enum TransferHookError {
+    /// Hook invoked outside of a Token-2022 transfer
+    #[msg("Transfer hook called outside of a transfer")]
+    NotTransferring = 6000,
+
+    /// Receiver has no investor attestation
+    #[msg("Receiver is not on the fund allowlist")]
+    ReceiverNotAllowlisted = 6001,
+}
+
+/// Extra accounts resolved by Token-2022 for each transfer (indices 5 to 11)
+fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![
+        // 5: Renewra fund program
+        ExtraAccountMeta::new_with_pubkey(&contracts::ID, false, false)?,
+        // 6: Governance PDA of the fund program
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[Seed::Literal {
+                bytes: Governance::SEED.to_vec(),
+            }],
+            false,
+            false,
+        )?,
+        // 7: Attestation PDA keyed by the destination token account owner (bytes 32..64)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal {
+                    bytes: InvestorAttestation::SEED.to_vec(),
+                },
+                Seed::AccountData {
+                    account_index: 2,
+                    data_index: 32,
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+        // 8: Fund-wide YieldState PDA (earning supply moves with checkpointed balances)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[Seed::Literal {
+                bytes: YieldState::SEED.to_vec(),
+            }],
+            false,
+            true,
+        )?,
+        // 9: Sender's UserYield PDA keyed by the source token account owner
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal {
+                    bytes: UserYield::SEED.to_vec(),
+                },
+                Seed::AccountData {
+                    account_index: 0,
+                    data_index: 32,
+                    length: 32,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // 10: Receiver's UserYield PDA keyed by the destination token account owner
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal {
+                    bytes: UserYield::SEED.to_vec(),
+                },
+                Seed::AccountData {
+                    account_index: 2,
+                    data_index: 32,
+                    length: 32,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // 11: This program's checkpoint authority PDA
+        ExtraAccountMeta::new_with_seeds(
+            &[Seed::Literal {
+                bytes: CHECKPOINT_AUTHORITY_SEED.to_vec(),
+            }],
+            false,
+            false,
+        )?,
+    ])
+}
+
+fn assert_is_transferring(source_token: &InterfaceAccount<TokenAccount>) -> Result<()> {
+    let source_info = source_token.to_account_info();
+    let data = source_info.try_borrow_data()?;
+    let account = StateWithExtensions::<SplTokenAccount>::unpack(&data)?;
+    let extension = account.get_extension::<TransferHookAccount>()?;
+    require!(
+        bool::from(extension.transferring),
+        TransferHookError::NotTransferring
+    );
+    Ok(())
+}
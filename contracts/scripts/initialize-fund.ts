@@ -1,7 +1,8 @@
 /**
  * Initialize the Renewra Fund on devnet
  * 
- * This script initializes the Governance, NavOracle, and RedemptionQueue PDAs.
+ * This script initializes the Governance, NavOracle, NavHistory, OracleConfig, RedemptionQueue,
+ * YieldState, FeeVault and USDC treasury PDAs.
  * Run this once before starting the oracle service.
  */
 
@@ -17,7 +18,16 @@ const PROGRAM_ID = new PublicKey("5nU2nHv2Pw9bWWL2BsTotX6mDaP1fTj1EZ7JMXAe6T5Z")
 // PDA seeds
 const GOVERNANCE_SEED = "governance";
 const NAV_ORACLE_SEED = "nav_oracle";
+const NAV_HISTORY_SEED = "nav_history";
+const ORACLE_CONFIG_SEED = "oracle_config";
 const REDEMPTION_QUEUE_SEED = "redemption_queue";
+const YIELD_STATE_SEED = "yield_state";
+const FEE_VAULT_SEED = "fee_vault";
+const TREASURY_SEED = "treasury";
+
+// Devnet USDC mint (override with USDC_MINT)
+const DEVNET_USDC_MINT = "4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU";
+const TOKEN_PROGRAM_ID = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
 
 async function main() {
     // Connect to devnet
@@ -50,12 +60,45 @@ async function main() {
     );
     console.log("NAV Oracle PDA:", navOraclePda.toBase58());
     
+    const [navHistoryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(NAV_HISTORY_SEED)],
+        PROGRAM_ID
+    );
+    console.log("NAV History PDA:", navHistoryPda.toBase58());
+    
+    const [oracleConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(ORACLE_CONFIG_SEED)],
+        PROGRAM_ID
+    );
+    console.log("Oracle Config PDA:", oracleConfigPda.toBase58());
+    
     const [redemptionQueuePda, redeemBump] = PublicKey.findProgramAddressSync(
         [Buffer.from(REDEMPTION_QUEUE_SEED)],
         PROGRAM_ID
     );
     console.log("Redemption Queue PDA:", redemptionQueuePda.toBase58());
     
+    const [yieldStatePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(YIELD_STATE_SEED)],
+        PROGRAM_ID
+    );
+    console.log("Yield State PDA:", yieldStatePda.toBase58());
+    
+    const [feeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(FEE_VAULT_SEED)],
+        PROGRAM_ID
+    );
+    console.log("Fee Vault PDA:", feeVaultPda.toBase58());
+    
+    const [treasuryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(TREASURY_SEED)],
+        PROGRAM_ID
+    );
+    console.log("Treasury PDA:", treasuryPda.toBase58());
+    
+    const usdcMint = new PublicKey(process.env.USDC_MINT ?? DEVNET_USDC_MINT);
+    console.log("USDC Mint:", usdcMint.toBase58());
+    
     // Create provider
     const wallet = new Wallet(deployerKeypair);
     const provider = new AnchorProvider(connection, wallet, { commitment: "confirmed" });
@@ -73,29 +116,42 @@ async function main() {
     // mint_fee_bps: u16 (2 bytes)
     // redemption_fee_bps: u16 (2 bytes)
     // initial_nav: u64 (8 bytes)
+    // guardian: Pubkey (32 bytes)
     const managementFee = 200; // 2%
     const mintFee = 50; // 0.5%
     const redeemFee = 100; // 1%
-    const initialNav = BigInt(5000); // $50.00 = 5000 cents
+    const initialNav = BigInt(50_000_000); // $50.00 with 6 decimals
+    const guardian = process.env.GUARDIAN
+        ? new PublicKey(process.env.GUARDIAN)
+        : deployerKeypair.publicKey; // pause-only kill switch
     
     // Instruction data: discriminator (8) + params
-    const data = Buffer.alloc(8 + 32 + 2 + 2 + 2 + 8);
+    const data = Buffer.alloc(8 + 32 + 2 + 2 + 2 + 8 + 32);
     discriminator.copy(data, 0);
     oracleKeypair.publicKey.toBuffer().copy(data, 8); // oracle_signer pubkey
     data.writeUInt16LE(managementFee, 40);
     data.writeUInt16LE(mintFee, 42);
     data.writeUInt16LE(redeemFee, 44);
     data.writeBigUInt64LE(initialNav, 46);
+    guardian.toBuffer().copy(data, 54);
     
     // Build instruction
-    // Accounts: authority, governance, nav_oracle, redemption_queue, system_program
+    // Accounts: authority, governance, nav_oracle, nav_history, oracle_config, redemption_queue,
+    // yield_state, fee_vault, usdc_mint, treasury, token_program, system_program
     const initializeIx = new anchor.web3.TransactionInstruction({
         programId: PROGRAM_ID,
         keys: [
             { pubkey: deployerKeypair.publicKey, isSigner: true, isWritable: true },
             { pubkey: governancePda, isSigner: false, isWritable: true },
             { pubkey: navOraclePda, isSigner: false, isWritable: true },
+            { pubkey: navHistoryPda, isSigner: false, isWritable: true },
+            { pubkey: oracleConfigPda, isSigner: false, isWritable: true },
             { pubkey: redemptionQueuePda, isSigner: false, isWritable: true },
+            { pubkey: yieldStatePda, isSigner: false, isWritable: true },
+            { pubkey: feeVaultPda, isSigner: false, isWritable: true },
+            { pubkey: usdcMint, isSigner: false, isWritable: false },
+            { pubkey: treasuryPda, isSigner: false, isWritable: true },
+            { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
         ],
         data,
@@ -6,12 +6,13 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, AnchorProvider, Wallet } from "@coral-xyz/anchor";
 import { Keypair, PublicKey, Connection, Transaction } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, createAssociatedTokenAccountInstruction, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
 import * as fs from "fs";
 import * as path from "path";
 
 const PROGRAM_ID = new PublicKey("5nU2nHv2Pw9bWWL2BsTotX6mDaP1fTj1EZ7JMXAe6T5Z");
-const USDC_MINT = new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU");
+const TRANSFER_HOOK_PROGRAM_ID = new PublicKey("3TtFMPAxzR1DDC4vymM9xfhmAh5Ktp8fs4fhGpd4hZFo");
+const DEFAULT_METADATA_URI = "https://renewra.vercel.app/rei-metadata.json";
 
 async function main() {
   const connection = new Connection("https://api.devnet.solana.com", "confirmed");
@@ -47,30 +48,21 @@ async function main() {
   );
   console.log("Governance PDA:", governance.toBase58());
   
-  const [treasury] = PublicKey.findProgramAddressSync(
-    [Buffer.from("treasury")],
-    PROGRAM_ID
-  );
-  console.log("Treasury PDA:", treasury.toBase58());
-  
-  // Derive treasury's USDC ATA
-  const treasuryUsdcAta = getAssociatedTokenAddressSync(
-    USDC_MINT,
-    treasury,
-    true // allowOwnerOffCurve
+  const [extraAccountMetaList] = PublicKey.findProgramAddressSync(
+    [Buffer.from("extra-account-metas"), reitMint.toBuffer()],
+    TRANSFER_HOOK_PROGRAM_ID
   );
-  console.log("Treasury USDC ATA:", treasuryUsdcAta.toBase58());
+  console.log("Transfer hook meta list PDA:", extraAccountMetaList.toBase58());
   
   // Check if mint already exists
   const mintAccount = await connection.getAccountInfo(reitMint);
-  const treasuryAccount = await connection.getAccountInfo(treasuryUsdcAta);
   
-  if (mintAccount && treasuryAccount) {
-    console.log("\n REIT Mint and Treasury already initialized!");
+  if (mintAccount) {
+    console.log("\n REIT Mint already initialized!");
     return;
   }
   
-  console.log("\n🚀 Initializing REIT Mint and Treasury USDC account...");
+  console.log("\n🚀 Initializing REIT Mint...");
   
   // Build instruction manually
   const SystemProgram = anchor.web3.SystemProgram;
@@ -80,41 +72,57 @@ async function main() {
   
   const keys = [
     { pubkey: deployerKeypair.publicKey, isSigner: true, isWritable: true },
-    { pubkey: governance, isSigner: false, isWritable: false },
+    { pubkey: governance, isSigner: false, isWritable: true },
     { pubkey: reitMint, isSigner: false, isWritable: true },
-    { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: TRANSFER_HOOK_PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+  ];
+  
+  // Transfer hook accounts for the new mint (sha256("global:initialize_extra_account_meta_list"))
+  const hookDiscriminator = Buffer.from([92, 197, 174, 197, 41, 124, 19, 3]);
+  
+  const hookKeys = [
+    { pubkey: deployerKeypair.publicKey, isSigner: true, isWritable: true },
+    { pubkey: extraAccountMetaList, isSigner: false, isWritable: true },
+    { pubkey: reitMint, isSigner: false, isWritable: false },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
   
-  const tx = new Transaction();
+  // Token-2022 name / symbol / URI on the mint (sha256("global:initialize_token_metadata"))
+  // Accounts: fund_authority (admin), governance, reit_mint, token_program, system_program
+  const metadataUri = process.env.REI_METADATA_URI ?? DEFAULT_METADATA_URI;
+  const metadataDiscriminator = Buffer.from([38, 126, 95, 111, 226, 208, 123, 23]);
+  const uriBytes = Buffer.from(metadataUri, "utf-8");
+  const uriLen = Buffer.alloc(4);
+  uriLen.writeUInt32LE(uriBytes.length);
+  console.log("Metadata URI:", metadataUri);
   
-  // Add initialize mint instruction if needed
-  if (!mintAccount) {
-    const initMintIx = new anchor.web3.TransactionInstruction({
+  const metadataKeys = [
+    { pubkey: deployerKeypair.publicKey, isSigner: true, isWritable: true },
+    { pubkey: governance, isSigner: false, isWritable: false },
+    { pubkey: reitMint, isSigner: false, isWritable: true },
+    { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+  ];
+  
+  const tx = new Transaction().add(
+    new anchor.web3.TransactionInstruction({
       programId: PROGRAM_ID,
       keys,
       data: discriminator,
-    });
-    tx.add(initMintIx);
-    console.log("  ➤ Creating REIT mint...");
-  }
-  
-  // Add create treasury ATA instruction if needed
-  if (!treasuryAccount) {
-    const createAtaIx = createAssociatedTokenAccountInstruction(
-      deployerKeypair.publicKey,
-      treasuryUsdcAta,
-      treasury,
-      USDC_MINT
-    );
-    tx.add(createAtaIx);
-    console.log("  ➤ Creating Treasury USDC account...");
-  }
-  
-  if (tx.instructions.length === 0) {
-    console.log(" Nothing to initialize - all accounts exist!");
-    return;
-  }
+    }),
+    new anchor.web3.TransactionInstruction({
+      programId: TRANSFER_HOOK_PROGRAM_ID,
+      keys: hookKeys,
+      data: hookDiscriminator,
+    }),
+    new anchor.web3.TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: metadataKeys,
+      data: Buffer.concat([metadataDiscriminator, uriLen, uriBytes]),
+    })
+  );
   
   try {
     const signature = await connection.sendTransaction(tx, [deployerKeypair]);
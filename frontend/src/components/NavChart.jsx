@@ -1,5 +1,5 @@
 import { AreaChart, Area, XAxis, YAxis, CartesianGrid, Tooltip, ResponsiveContainer } from 'recharts';
-import { formatUSD, formatDate } from '../lib/solana';
+import { formatDollars, formatDate } from '../lib/solana';
 import { TrendingUp } from 'lucide-react';
 
 /**
@@ -11,7 +11,7 @@ export default function NavChart({ data = [], height = 280 }) {
   // Format data for recharts
   const chartData = data.map(item => ({
     ...item,
-    navFormatted: item.nav / 100,
+    navFormatted: item.nav,
     dateFormatted: formatDate(item.timestamp),
   }));
 
@@ -20,7 +20,7 @@ export default function NavChart({ data = [], height = 280 }) {
       return (
         <div className="glass rounded-xl px-4 py-3 shadow-xl border border-white/10">
           <p className="text-gray-400 text-xs mb-1">{payload[0].payload.dateFormatted}</p>
-          <p className="text-xl font-bold gradient-text">{formatUSD(payload[0].payload.nav)}</p>
+          <p className="text-xl font-bold gradient-text">{formatDollars(payload[0].payload.nav)}</p>
         </div>
       );
     }
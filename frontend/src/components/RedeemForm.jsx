@@ -17,13 +17,12 @@ export default function RedeemForm() {
     const [localError, setLocalError] = useState(null);
 
     const currentNav = data?.currentNav || 0;
-    const navDisplay = toDisplayAmount(currentNav, 2);
     const userReiBalance = toDisplayAmount(data?.userReiBalance || 0, 6);
     const redeemFeeBps = data?.redeemFeeBps || 100;
 
     // Calculate USDC received
     const tokens = parseFloat(tokenAmount || 0);
-    const grossUsdcCents = tokens * navDisplay * 100;
+    const grossUsdcCents = tokens * currentNav * 100;
     const feeAmount = (grossUsdcCents * redeemFeeBps) / 10000;
     const netUsdcCents = grossUsdcCents - feeAmount;
 
@@ -175,7 +174,7 @@ export default function RedeemForm() {
                             <div className="bg-white/[0.03] border border-white/5 rounded-xl p-6 space-y-4 animate-reveal">
                                 <div className="flex justify-between text-sm">
                                     <span className="text-white/40">Current NAV</span>
-                                    <span className="text-white">${navDisplay.toFixed(2)}</span>
+                                    <span className="text-white">${currentNav.toFixed(2)}</span>
                                 </div>
                                 <div className="flex justify-between text-sm">
                                     <span className="text-white/40">Gross Value</span>
@@ -1,4 +1,4 @@
-import { formatUSD, formatCompact } from '../lib/solana';
+import { formatUSD, formatDollars, formatCompact } from '../lib/solana';
 import { TrendingUp, TrendingDown, Coins, DollarSign, Sprout, BarChart3, Wallet, Clock } from 'lucide-react';
 
 /**
@@ -78,7 +78,7 @@ export function FundStatsGrid({ stats, loading = false }) {
   const statItems = [
     {
       label: 'Current NAV',
-      value: formatUSD(stats.currentNav),
+      value: formatDollars(stats.currentNav),
       subValue: '+0.54% (30d)',
       trend: 'up',
       icon: TrendingUp,
@@ -2,7 +2,7 @@ import { useState } from 'react';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { useWalletModal } from '@solana/wallet-adapter-react-ui';
 import { useReiToken } from '../lib/useReiToken';
-import { formatUSD } from '../lib/solana';
+import { formatDollars, quoteSubscription } from '../lib/solana';
 import { toDisplayAmount } from '../lib/types';
 import { CheckCircle, AlertCircle, ArrowRight, Wallet, Coins } from 'lucide-react';
 
@@ -20,16 +20,20 @@ export default function SubscribeForm() {
   const [txSignature, setTxSignature] = useState(null);
   const [localError, setLocalError] = useState(null);
 
-  const currentNav = data?.currentNav || 5000;
-  const mintFeeBps = data?.mintFeeBps || 50;
+  // Quote at the NAV subscribe prices at (spot or TWAP per governance) with the on-chain mint fee
+  const pricingNav = data?.pricingNav;
+  const pricingMode = data?.pricingMode;
+  const mintFeeBps = data?.mintFeeBps;
   const userUsdcBalance = toDisplayAmount(data?.userUsdcBalance || 0, 6);
 
   // Calculate tokens received
   const usdcValue = parseFloat(usdcAmount || 0);
-  const usdcCents = usdcValue * 100;
-  const feeAmount = (usdcCents * mintFeeBps) / 10000;
-  const netUsdcCents = usdcCents - feeAmount;
-  const tokensReceived = currentNav > 0 ? (netUsdcCents / currentNav) : 0;
+  const quote = mintFeeBps != null && pricingNav > 0
+    ? quoteSubscription(Math.floor(usdcValue * 1_000_000), mintFeeBps, pricingNav)
+    : null;
+  const feeAmount = toDisplayAmount(quote?.feeAmount || 0, 6);
+  const netUsdc = toDisplayAmount(quote?.netUsdc || 0, 6);
+  const tokensReceived = toDisplayAmount(quote?.tokens || 0, 6);
 
   const handleSubmit = async (e) => {
     e.preventDefault();
@@ -51,8 +55,13 @@ export default function SubscribeForm() {
       return;
     }
 
+    if (!quote) {
+      setLocalError('Fund pricing is not available yet, please try again');
+      return;
+    }
+
     try {
-      const signature = await subscribe(usdcValue);
+      const signature = await subscribe(usdcValue, tokensReceived);
       setTxSignature(signature);
       setUsdcAmount('');
     } catch (err) {
@@ -148,21 +157,21 @@ export default function SubscribeForm() {
       </div>
 
       {/* Calculation Breakdown */}
-      {usdcAmount && parseFloat(usdcAmount) > 0 && (
+      {usdcAmount && parseFloat(usdcAmount) > 0 && quote && (
         <div className="bg-white/[0.03] border border-white/5 rounded-xl p-6 space-y-4 animate-reveal">
           <div className="flex justify-between text-sm">
-            <span className="text-white/40">Current NAV</span>
-            <span className="text-white">{formatUSD(currentNav)}</span>
+            <span className="text-white/40">{pricingMode === 'twap' ? 'Pricing NAV (TWAP)' : 'Current NAV'}</span>
+            <span className="text-white">{formatDollars(toDisplayAmount(pricingNav, 6))}</span>
           </div>
 
           <div className="flex justify-between text-sm">
             <span className="text-white/40">Mint Fee ({(mintFeeBps / 100).toFixed(2)}%)</span>
-            <span className="text-white/60">-{formatUSD(feeAmount)}</span>
+            <span className="text-white/60">-{formatDollars(feeAmount)}</span>
           </div>
 
           <div className="flex justify-between text-sm">
             <span className="text-white/40">Net Investment</span>
-            <span className="text-white">{formatUSD(netUsdcCents)}</span>
+            <span className="text-white">{formatDollars(netUsdc)}</span>
           </div>
 
           <div className="border-t border-white/5 pt-4 flex justify-between items-center">
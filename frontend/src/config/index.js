@@ -10,6 +10,8 @@ export const config = {
     rpcUrl: import.meta.env.VITE_SOLANA_RPC_URL || 'https://api.devnet.solana.com',
     programId: import.meta.env.VITE_PROGRAM_ID,
     usdcMint: import.meta.env.VITE_USDC_MINT,
+    transferHookProgramId:
+      import.meta.env.VITE_TRANSFER_HOOK_PROGRAM_ID || '3TtFMPAxzR1DDC4vymM9xfhmAh5Ktp8fs4fhGpd4hZFo',
   },
 
   // PDA Seeds
@@ -19,6 +21,16 @@ export const config = {
     treasury: import.meta.env.VITE_TREASURY_SEED || 'treasury',
     reitMint: import.meta.env.VITE_REIT_MINT_SEED || 'reit_mint',
     redemptionQueue: import.meta.env.VITE_REDEMPTION_QUEUE_SEED || 'redemption_queue',
+    redemptionRequest: 'redemption_request',
+    redeemEscrow: 'redeem_escrow',
+    navHistory: 'nav_history',
+    feeVault: 'fee_vault',
+    yieldState: 'yield_state',
+    userYield: 'user_yield',
+    investorPosition: 'investor_position',
+    investorAttestation: 'investor_attestation',
+    extraAccountMetas: 'extra-account-metas',
+    checkpointAuthority: 'checkpoint_authority',
   },
 
   // App Configuration
@@ -38,6 +50,7 @@ export const config = {
     confirmTimeout: 60000, // 60 seconds
     maxRetries: 3,
     cacheTTL: 30000, // 30 seconds
+    slippageToleranceBps: 50, // min_tokens_out = quote - 0.5%
   },
 
   // UI Configuration
@@ -67,7 +67,7 @@ export const MOCK_PROJECTS = [
 ];
 
 export const MOCK_FUND_STATS = {
-  currentNav: 5027, // $50.27
+  currentNav: 50.27,
   totalSupply: 8150000, // 8.15M tokens
   fundValue: 410000000, // $410M
   ytdYield: 7.2,
@@ -76,14 +76,14 @@ export const MOCK_FUND_STATS = {
 };
 
 export const MOCK_NAV_HISTORY = [
-  { nav: 5000, timestamp: Date.now() - 86400000 * 30 },
-  { nav: 5012, timestamp: Date.now() - 86400000 * 25 },
-  { nav: 5008, timestamp: Date.now() - 86400000 * 20 },
-  { nav: 5015, timestamp: Date.now() - 86400000 * 15 },
-  { nav: 5020, timestamp: Date.now() - 86400000 * 10 },
-  { nav: 5018, timestamp: Date.now() - 86400000 * 5 },
-  { nav: 5025, timestamp: Date.now() - 86400000 * 2 },
-  { nav: 5027, timestamp: Date.now() },
+  { nav: 50.00, timestamp: Date.now() - 86400000 * 30 },
+  { nav: 50.12, timestamp: Date.now() - 86400000 * 25 },
+  { nav: 50.08, timestamp: Date.now() - 86400000 * 20 },
+  { nav: 50.15, timestamp: Date.now() - 86400000 * 15 },
+  { nav: 50.20, timestamp: Date.now() - 86400000 * 10 },
+  { nav: 50.18, timestamp: Date.now() - 86400000 * 5 },
+  { nav: 50.25, timestamp: Date.now() - 86400000 * 2 },
+  { nav: 50.27, timestamp: Date.now() },
 ];
 
 export const MOCK_USER_HOLDINGS = {
@@ -125,7 +125,7 @@ export const MOCK_TRANSACTIONS = [
     type: 'subscribe',
     amount: 10000,
     tokens: 200,
-    nav: 5000,
+    nav: 50.00,
     timestamp: Date.now() - 604800000,
     signature: '3xMN...9pRe',
   },
@@ -134,7 +134,7 @@ export const MOCK_TRANSACTIONS = [
     type: 'subscribe',
     amount: 25000,
     tokens: 500,
-    nav: 5000,
+    nav: 50.00,
     timestamp: Date.now() - 432000000,
     signature: '7vHZ...4kFq',
   },
@@ -143,7 +143,7 @@ export const MOCK_TRANSACTIONS = [
     type: 'yield',
     amount: 125,
     tokens: 0,
-    nav: 5020,
+    nav: 50.20,
     timestamp: Date.now() - 86400000,
     signature: '9kLp...2wXy',
   },
@@ -4,8 +4,8 @@
  * Production-ready with error handling, caching, and rate limiting
  */
 
-import { Connection, PublicKey, TransactionInstruction, Transaction } from '@solana/web3.js';
-import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Connection, PublicKey, SystemProgram, TransactionInstruction, Transaction } from '@solana/web3.js';
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
 import config from '../config';
 
 // ============================================================================
@@ -15,6 +15,17 @@ import config from '../config';
 export const PROGRAM_ID = new PublicKey(config.solana.programId);
 export const RPC_URL = config.solana.rpcUrl;
 export const USDC_MINT = new PublicKey(config.solana.usdcMint);
+export const TRANSFER_HOOK_PROGRAM_ID = new PublicKey(config.solana.transferHookProgramId);
+
+// On-chain NAV is fixed point with 6 decimals (1_000_000 = $1.00)
+export const NAV_DECIMALS = 6;
+const NAV_SCALE = 10 ** NAV_DECIMALS;
+const DEFAULT_NAV = 50_000_000 / NAV_SCALE; // $50.00
+
+// NavHistory ring buffer (zero-copy account, fixed offsets)
+const NAV_HISTORY_CAPACITY = 64;
+const NAV_HISTORY_ENTRY_SIZE = 64;
+const NAV_HISTORY_SIZE = 4136;
 
 // PDA Seeds
 const GOVERNANCE_SEED = config.seeds.governance;
@@ -38,6 +49,21 @@ export const REDEMPTION_QUEUE_PDA = PublicKey.findProgramAddressSync(
   PROGRAM_ID
 )[0];
 
+export const NAV_HISTORY_PDA = PublicKey.findProgramAddressSync(
+  [Buffer.from(config.seeds.navHistory)],
+  PROGRAM_ID
+)[0];
+
+export const FEE_VAULT_PDA = PublicKey.findProgramAddressSync(
+  [Buffer.from(config.seeds.feeVault)],
+  PROGRAM_ID
+)[0];
+
+export const YIELD_STATE_PDA = PublicKey.findProgramAddressSync(
+  [Buffer.from(config.seeds.yieldState)],
+  PROGRAM_ID
+)[0];
+
 // Anchor discriminators (sha256("global:<instruction_name>")[0:8])
 export const DISCRIMINATORS = {
   subscribe: Buffer.from([254, 28, 191, 138, 156, 179, 183, 53]),
@@ -124,6 +150,69 @@ export function deriveReitMintPDA() {
   );
 }
 
+/**
+ * Derive a per-owner PDA of the fund program (user_yield, investor_position, ...)
+ * @param {string} seed - Seed prefix from config.seeds
+ * @param {PublicKey} owner - Wallet the PDA belongs to
+ * @returns {[PublicKey, number]}
+ */
+function deriveOwnerPDA(seed, owner) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(seed), owner.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derive a holder's UserYield PDA (yield checkpoint)
+ * @param {PublicKey} owner
+ * @returns {[PublicKey, number]}
+ */
+export function deriveUserYieldPDA(owner) {
+  return deriveOwnerPDA(config.seeds.userYield, owner);
+}
+
+/**
+ * Derive an investor's InvestorPosition PDA (lifetime subscriptions)
+ * @param {PublicKey} owner
+ * @returns {[PublicKey, number]}
+ */
+export function deriveInvestorPositionPDA(owner) {
+  return deriveOwnerPDA(config.seeds.investorPosition, owner);
+}
+
+/**
+ * Derive an investor's InvestorAttestation PDA (KYC allowlist entry)
+ * @param {PublicKey} owner
+ * @returns {[PublicKey, number]}
+ */
+export function deriveAttestationPDA(owner) {
+  return deriveOwnerPDA(config.seeds.investorAttestation, owner);
+}
+
+/**
+ * Derive a requester's redemption escrow token account PDA
+ * @param {PublicKey} owner
+ * @returns {[PublicKey, number]}
+ */
+export function deriveRedeemEscrowPDA(owner) {
+  return deriveOwnerPDA(config.seeds.redeemEscrow, owner);
+}
+
+/**
+ * Derive the RedemptionRequest PDA for a request id
+ * @param {number} requestId
+ * @returns {[PublicKey, number]}
+ */
+export function deriveRedemptionRequestPDA(requestId) {
+  const idBytes = Buffer.alloc(8);
+  idBytes.writeBigUInt64LE(BigInt(requestId));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(config.seeds.redemptionRequest), idBytes],
+    PROGRAM_ID
+  );
+}
+
 // ============================================================================
 // Account Decoding
 // ============================================================================
@@ -131,8 +220,11 @@ export function deriveReitMintPDA() {
 /**
  * Decode NavOracle account data
  * Layout: 8 (discriminator) + 8 (latest_nav) + 8 (previous_nav) + 8 (timestamp) + 1 (bump)
+ *         + 16 (cumulative_nav_seconds) + 8 (cumulative_timestamp)
+ * NAV values are raw 6-decimal fixed point
  * @param {Buffer} data - Raw account data
- * @returns {{latestNav: number, previousNav: number, timestamp: number, bump: number}}
+ * @returns {{latestNav: number, previousNav: number, timestamp: number, bump: number,
+ *           cumulativeNavSeconds: bigint, cumulativeTimestamp: number}}
  */
 export function decodeNavOracle(data) {
   if (!data || data.length < 33) {
@@ -146,17 +238,96 @@ export function decodeNavOracle(data) {
     previousNav: Number(view.getBigUint64(16, true)),
     timestamp: Number(view.getBigInt64(24, true)),
     bump: data[32],
+    // TWAP accumulator (absent on oracles still in the 33-byte baseline layout)
+    cumulativeNavSeconds: data.length >= 57 ? readU128(view, 33) : 0n,
+    cumulativeTimestamp: data.length >= 57 ? Number(view.getBigInt64(49, true)) : 0,
   };
 }
 
 /**
- * Decode Governance account data
- * Layout: 8 + 32 (admin) + 32 (oracle) + 2 + 2 + 2 + 1 + 1
+ * Decode NavHistory account data (4136 bytes)
+ * Layout: 8 (discriminator) + 64 * [8 (nav) + 8 (timestamp) + 32 (oracle) + 16 (cumulative_nav_seconds)]
+ *         + 8 (head) @4104 + 8 (count) @4112 + 1 (bump) @4120 + 15 (padding)
+ * @param {Buffer} data - Raw account data
+ * @returns {{entries: Array<{nav: number, timestamp: number, oracle: PublicKey, cumulativeNavSeconds: bigint}>, bump: number}}
+ *          Entries are ordered newest first
+ */
+export function decodeNavHistory(data) {
+  if (!data || data.length < NAV_HISTORY_SIZE) {
+    throw new Error('Invalid NavHistory data');
+  }
+  
+  const view = new DataView(data.buffer, data.byteOffset);
+  const head = Number(view.getBigUint64(4104, true));
+  const count = Math.min(Number(view.getBigUint64(4112, true)), NAV_HISTORY_CAPACITY);
+  
+  const entries = [];
+  for (let age = 0; age < count; age++) {
+    const index = (head + NAV_HISTORY_CAPACITY - 1 - age) % NAV_HISTORY_CAPACITY;
+    const offset = 8 + index * NAV_HISTORY_ENTRY_SIZE;
+    
+    entries.push({
+      nav: Number(view.getBigUint64(offset, true)),
+      timestamp: Number(view.getBigInt64(offset + 8, true)),
+      oracle: new PublicKey(data.slice(offset + 16, offset + 48)),
+      cumulativeNavSeconds: readU128(view, offset + 48),
+    });
+  }
+  
+  return {
+    entries,
+    bump: data[4120],
+  };
+}
+
+/**
+ * Read a little-endian u128 as a BigInt
+ */
+function readU128(view, offset) {
+  return view.getBigUint64(offset, true) + (view.getBigUint64(offset + 8, true) << 64n);
+}
+
+/**
+ * Time-weighted average NAV over the last windowSecs seconds (mirrors NavOracle::twap)
+ * @param {Object} oracle - Decoded NavOracle
+ * @param {Array} historyEntries - Decoded NavHistory entries, newest first
+ * @param {number} now - Unix timestamp (seconds)
+ * @param {number} windowSecs - Averaging window (seconds)
+ * @returns {number|null} Raw 6-decimal NAV, or null without any history checkpoint
+ */
+export function computeTwap(oracle, historyEntries, now, windowSecs) {
+  const windowStart = now - windowSecs;
+  
+  // Newest checkpoint at or before the window start, or the oldest one retained
+  let checkpoint = null;
+  for (const entry of historyEntries) {
+    checkpoint = entry;
+    if (entry.timestamp <= windowStart) break;
+  }
+  if (!checkpoint) return null;
+  
+  const start = Math.max(windowStart, checkpoint.timestamp);
+  if (now <= start) return oracle.latestNav;
+  
+  const cumulativeStart =
+    checkpoint.cumulativeNavSeconds + BigInt(checkpoint.nav) * BigInt(start - checkpoint.timestamp);
+  const elapsed = Math.max(now - oracle.cumulativeTimestamp, 0);
+  const cumulativeNow = oracle.cumulativeNavSeconds + BigInt(oracle.latestNav) * BigInt(elapsed);
+  
+  return Number((cumulativeNow - cumulativeStart) / BigInt(now - start));
+}
+
+/**
+ * Decode Governance account data (523 bytes)
+ * Layout: 8 + 32 (admin) + 32 (oracle) + 2 + 2 + 2 (fees) + 1 (paused) + 32 (guardian)
+ *         + 32 (pending_admin) + 32 (next_oracle) + 8 + 8 (rotation slots) + 2 + 1 + 8 (NAV band/age)
+ *         + 1 (pricing_mode) @202 + 8 (twap_window) @203 + 32 (treasury) @211 + 32 (usdc_mint) + 32 (reit_mint)
+ *         + 32 (fee_recipient) @307 + ... + 1 (require_kyc) @521 + 1 (bump) @522
  * @param {Buffer} data - Raw account data
  * @returns {Object}
  */
 export function decodeGovernance(data) {
-  if (!data || data.length < 80) {
+  if (!data || data.length < 523) {
     throw new Error('Invalid Governance data');
   }
   
@@ -169,7 +340,14 @@ export function decodeGovernance(data) {
     mintFeeBps: view.getUint16(74, true),
     redemptionFeeBps: view.getUint16(76, true),
     paused: data[78] === 1,
-    bump: data[79],
+    pricingMode: data[202] === 1 ? 'twap' : 'spot',
+    twapWindowSecs: Number(view.getBigInt64(203, true)),
+    treasury: new PublicKey(data.slice(211, 243)),
+    usdcMint: new PublicKey(data.slice(243, 275)),
+    reitMint: new PublicKey(data.slice(275, 307)),
+    feeRecipient: new PublicKey(data.slice(307, 339)),
+    requireKyc: data[521] === 1,
+    bump: data[522],
   };
 }
 
@@ -220,7 +398,7 @@ export function decodeRedemptionQueue(data) {
 /**
  * Fetch current NAV from on-chain oracle
  * @param {boolean} forceRefresh - Skip cache and fetch fresh data
- * @returns {Promise<{navCents: number, previousNavCents: number, timestamp: Date}>}
+ * @returns {Promise<{nav: number, previousNav: number, timestamp: Date}>} NAV in dollars
  */
 export async function fetchCurrentNav(forceRefresh = false) {
   const cacheKey = 'nav';
@@ -237,7 +415,7 @@ export async function fetchCurrentNav(forceRefresh = false) {
     
     if (!accountInfo) {
       console.warn('NavOracle account not found, using default');
-      const defaultVal = { navCents: 5000, previousNavCents: 5000, timestamp: new Date() };
+      const defaultVal = { nav: DEFAULT_NAV, previousNav: DEFAULT_NAV, timestamp: new Date() };
       setCache(cacheKey, defaultVal);
       return defaultVal;
     }
@@ -245,8 +423,8 @@ export async function fetchCurrentNav(forceRefresh = false) {
     const decoded = decodeNavOracle(accountInfo.data);
     
     const result = {
-      navCents: decoded.latestNav,
-      previousNavCents: decoded.previousNav,
+      nav: decoded.latestNav / NAV_SCALE,
+      previousNav: decoded.previousNav / NAV_SCALE,
       timestamp: new Date(decoded.timestamp * 1000),
     };
     
@@ -254,11 +432,66 @@ export async function fetchCurrentNav(forceRefresh = false) {
     return result;
   } catch (error) {
     console.error('Error fetching NAV:', error);
-    const defaultVal = { navCents: 5000, previousNavCents: 5000, timestamp: new Date() };
+    const defaultVal = { nav: DEFAULT_NAV, previousNav: DEFAULT_NAV, timestamp: new Date() };
     return defaultVal;
   }
 }
 
+/**
+ * Fetch the NAV subscriptions are priced at: spot, or the TWAP over
+ * governance.twapWindowSecs (mirrors NavOracle::pricing_nav)
+ * @param {Object} governance - Decoded governance
+ * @param {boolean} forceRefresh - Skip cache
+ * @returns {Promise<{nav: number, rawNav: number, mode: string}|null>} null when no price is available
+ */
+export async function fetchPricingNav(governance, forceRefresh = false) {
+  const cacheKey = 'pricingNav';
+  
+  if (!forceRefresh) {
+    const cached = getCached(cacheKey);
+    if (cached) return cached;
+  }
+  
+  const connection = getConnection();
+  
+  try {
+    const [oracleInfo, historyInfo] = await connection.getMultipleAccountsInfo([
+      NAV_ORACLE_PDA,
+      NAV_HISTORY_PDA,
+    ]);
+    
+    if (!oracleInfo) {
+      return null;
+    }
+    
+    const oracle = decodeNavOracle(oracleInfo.data);
+    let rawNav = oracle.latestNav;
+    if (governance.pricingMode === 'twap') {
+      if (!historyInfo) {
+        return null;
+      }
+      const { entries } = decodeNavHistory(historyInfo.data);
+      rawNav = computeTwap(oracle, entries, Math.floor(Date.now() / 1000), governance.twapWindowSecs);
+    }
+    
+    if (!rawNav) {
+      return null;
+    }
+    
+    const result = {
+      nav: rawNav / NAV_SCALE,
+      rawNav,
+      mode: governance.pricingMode,
+    };
+    
+    setCache(cacheKey, result);
+    return result;
+  } catch (error) {
+    console.error('Error fetching pricing NAV:', error);
+    return null;
+  }
+}
+
 /**
  * Fetch governance parameters
  * @param {boolean} forceRefresh - Skip cache
@@ -321,15 +554,33 @@ export async function fetchRedemptionQueue(forceRefresh = false) {
   }
 }
 
+/**
+ * Fetch the id the next redemption request will be assigned
+ * RedemptionQueue layout: 8 (discriminator) + 8 (next_request_id) + 8 (open_requests) + 1 (bump)
+ * @returns {Promise<number|null>}
+ */
+export async function fetchNextRedemptionRequestId() {
+  const connection = getConnection();
+  const accountInfo = await connection.getAccountInfo(REDEMPTION_QUEUE_PDA);
+  
+  if (!accountInfo || accountInfo.data.length < 25) {
+    return null;
+  }
+  
+  const view = new DataView(accountInfo.data.buffer, accountInfo.data.byteOffset);
+  return Number(view.getBigUint64(8, true));
+}
+
 /**
  * Fetch SPL token balance for a user
  * @param {PublicKey} owner - Wallet public key
  * @param {PublicKey} mint - Token mint address
  * @param {boolean} forceRefresh - Skip cache
  * @param {boolean} isPda - Whether owner is a PDA (allows off-curve)
+ * @param {PublicKey} programId - Token program owning the mint (Token-2022 for REI)
  * @returns {Promise<number>} Balance in token base units
  */
-export async function fetchTokenBalance(owner, mint, forceRefresh = false, isPda = false) {
+export async function fetchTokenBalance(owner, mint, forceRefresh = false, isPda = false, programId = TOKEN_PROGRAM_ID) {
   const cacheKey = `balance:${owner.toBase58()}:${mint.toBase58()}`;
   
   if (!forceRefresh) {
@@ -341,7 +592,7 @@ export async function fetchTokenBalance(owner, mint, forceRefresh = false, isPda
   
   try {
     // Use allowOwnerOffCurve for PDAs like treasury
-    const ata = getAssociatedTokenAddressSync(mint, owner, isPda);
+    const ata = getAssociatedTokenAddressSync(mint, owner, isPda, programId);
     const accountInfo = await connection.getAccountInfo(ata);
     
     if (!accountInfo) {
@@ -399,33 +650,110 @@ export async function fetchMintSupply(mint, forceRefresh = false) {
 // Transaction Building
 // ============================================================================
 
+/**
+ * Quote a subscription the way subscribe prices it: fee rounded up, REI rounded down
+ * @param {number} usdcAmount - USDC in base units (6 decimals)
+ * @param {number} mintFeeBps - Governance mint fee in basis points
+ * @param {number} pricingNav - Raw 6-decimal pricing NAV
+ * @returns {{feeAmount: number, netUsdc: number, tokens: number}} Base units (6 decimals)
+ */
+export function quoteSubscription(usdcAmount, mintFeeBps, pricingNav) {
+  const usdc = BigInt(usdcAmount);
+  const fee = (usdc * BigInt(mintFeeBps) + 9_999n) / 10_000n;
+  const netUsdc = usdc - fee;
+  const tokens = pricingNav > 0 ? (netUsdc * BigInt(NAV_SCALE)) / BigInt(pricingNav) : 0n;
+  
+  return {
+    feeAmount: Number(fee),
+    netUsdc: Number(netUsdc),
+    tokens: Number(tokens),
+  };
+}
+
+/**
+ * Compute min_tokens_out from the quoted REI amount less the slippage tolerance
+ * @param {number} quotedTokens - REI shown to the user (display units)
+ * @param {number} toleranceBps - Allowed shortfall in basis points
+ * @returns {number} Minimum REI in base units (6 decimals)
+ */
+export function minTokensOutFromQuote(quotedTokens, toleranceBps = config.transaction.slippageToleranceBps) {
+  const quotedBase = Math.floor(quotedTokens * 1_000_000);
+  return Math.floor((quotedBase * (10_000 - toleranceBps)) / 10_000);
+}
+
+/**
+ * Extra accounts the REI transfer hook needs, forwarded as remaining accounts by
+ * fund instructions that move REI (order of the hook's ExtraAccountMetaList)
+ * @param {PublicKey} sourceOwner - Owner of the sending REI account
+ * @param {PublicKey} destinationOwner - Owner of the receiving REI account
+ * @param {PublicKey} reitMint - REI token mint
+ * @returns {Array<{pubkey: PublicKey, isSigner: boolean, isWritable: boolean}>}
+ */
+export function transferHookAccounts(sourceOwner, destinationOwner, reitMint) {
+  const [extraAccountMetaList] = PublicKey.findProgramAddressSync(
+    [Buffer.from(config.seeds.extraAccountMetas), reitMint.toBuffer()],
+    TRANSFER_HOOK_PROGRAM_ID
+  );
+  const [checkpointAuthority] = PublicKey.findProgramAddressSync(
+    [Buffer.from(config.seeds.checkpointAuthority)],
+    TRANSFER_HOOK_PROGRAM_ID
+  );
+  
+  return [
+    { pubkey: TRANSFER_HOOK_PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: extraAccountMetaList, isSigner: false, isWritable: false },
+    { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: GOVERNANCE_PDA, isSigner: false, isWritable: false },
+    { pubkey: deriveAttestationPDA(destinationOwner)[0], isSigner: false, isWritable: false },
+    { pubkey: YIELD_STATE_PDA, isSigner: false, isWritable: true },
+    { pubkey: deriveUserYieldPDA(sourceOwner)[0], isSigner: false, isWritable: true },
+    { pubkey: deriveUserYieldPDA(destinationOwner)[0], isSigner: false, isWritable: true },
+    { pubkey: checkpointAuthority, isSigner: false, isWritable: false },
+  ];
+}
+
 /**
  * Build subscribe instruction
  * @param {PublicKey} user - User wallet
  * @param {number} usdcAmount - Amount in USDC smallest units (6 decimals)
- * @param {PublicKey} reitMint - REI token mint
- * @param {PublicKey} treasury - Treasury token account
+ * @param {number} minTokensOut - Minimum REI to receive in base units (see minTokensOutFromQuote)
+ * @param {Object} governance - Decoded governance (treasury, reitMint, feeRecipient)
+ * @param {boolean} hasAttestation - Whether the user holds an InvestorAttestation
  * @returns {TransactionInstruction}
  */
-export function buildSubscribeInstruction(user, usdcAmount, reitMint, treasury) {
+export function buildSubscribeInstruction(user, usdcAmount, minTokensOut, governance, hasAttestation) {
+  const { treasury, reitMint, feeRecipient } = governance;
   const userUsdcAccount = getAssociatedTokenAddressSync(USDC_MINT, user);
-  const userReitAccount = getAssociatedTokenAddressSync(reitMint, user);
+  const userReitAccount = getAssociatedTokenAddressSync(reitMint, user, false, TOKEN_2022_PROGRAM_ID);
+  const feeRecipientAccount = getAssociatedTokenAddressSync(reitMint, feeRecipient, true, TOKEN_2022_PROGRAM_ID);
   
-  // Instruction data: discriminator (8) + usdc_amount (u64, 8)
-  const data = Buffer.alloc(16);
+  // Instruction data: discriminator (8) + usdc_amount (u64, 8) + min_tokens_out (u64, 8)
+  const data = Buffer.alloc(24);
   DISCRIMINATORS.subscribe.copy(data, 0);
   const view = new DataView(data.buffer);
   view.setBigUint64(8, BigInt(usdcAmount), true);
+  view.setBigUint64(16, BigInt(minTokensOut), true);
   
   const keys = [
     { pubkey: user, isSigner: true, isWritable: true },
-    { pubkey: GOVERNANCE_PDA, isSigner: false, isWritable: false },
+    { pubkey: GOVERNANCE_PDA, isSigner: false, isWritable: true },
     { pubkey: NAV_ORACLE_PDA, isSigner: false, isWritable: false },
+    { pubkey: NAV_HISTORY_PDA, isSigner: false, isWritable: false },
     { pubkey: userUsdcAccount, isSigner: false, isWritable: true },
+    { pubkey: USDC_MINT, isSigner: false, isWritable: false },
     { pubkey: treasury, isSigner: false, isWritable: true },
     { pubkey: userReitAccount, isSigner: false, isWritable: true },
     { pubkey: reitMint, isSigner: false, isWritable: true },
+    { pubkey: feeRecipientAccount, isSigner: false, isWritable: true },
+    { pubkey: FEE_VAULT_PDA, isSigner: false, isWritable: true },
+    { pubkey: YIELD_STATE_PDA, isSigner: false, isWritable: true },
+    { pubkey: deriveUserYieldPDA(user)[0], isSigner: false, isWritable: true },
+    { pubkey: deriveInvestorPositionPDA(user)[0], isSigner: false, isWritable: true },
+    // Optional account: the program id stands in for "none"
+    { pubkey: hasAttestation ? deriveAttestationPDA(user)[0] : PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
   
   return new TransactionInstruction({
@@ -438,12 +766,14 @@ export function buildSubscribeInstruction(user, usdcAmount, reitMint, treasury)
 /**
  * Build request_redeem instruction
  * @param {PublicKey} requester - User wallet
- * @param {number} tokenAmount - Amount of REI tokens to redeem
+ * @param {number} tokenAmount - Amount of REI tokens to redeem (base units)
+ * @param {number} requestId - Id the request will get (RedemptionQueue.next_request_id)
  * @param {PublicKey} reitMint - REI token mint
+ * @param {boolean} hasAttestation - Whether the requester holds an InvestorAttestation
  * @returns {TransactionInstruction}
  */
-export function buildRequestRedeemInstruction(requester, tokenAmount, reitMint) {
-  const userReitAccount = getAssociatedTokenAddressSync(reitMint, requester);
+export function buildRequestRedeemInstruction(requester, tokenAmount, requestId, reitMint, hasAttestation) {
+  const userReitAccount = getAssociatedTokenAddressSync(reitMint, requester, false, TOKEN_2022_PROGRAM_ID);
   
   // Instruction data: discriminator (8) + token_amount (u64, 8)
   const data = Buffer.alloc(16);
@@ -455,8 +785,18 @@ export function buildRequestRedeemInstruction(requester, tokenAmount, reitMint)
     { pubkey: requester, isSigner: true, isWritable: true },
     { pubkey: GOVERNANCE_PDA, isSigner: false, isWritable: false },
     { pubkey: REDEMPTION_QUEUE_PDA, isSigner: false, isWritable: true },
+    { pubkey: deriveRedemptionRequestPDA(requestId)[0], isSigner: false, isWritable: true },
     { pubkey: userReitAccount, isSigner: false, isWritable: true },
-    { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: deriveRedeemEscrowPDA(requester)[0], isSigner: false, isWritable: true },
+    { pubkey: reitMint, isSigner: false, isWritable: false },
+    { pubkey: YIELD_STATE_PDA, isSigner: false, isWritable: true },
+    { pubkey: deriveUserYieldPDA(requester)[0], isSigner: false, isWritable: true },
+    // Optional account: the program id stands in for "none"
+    { pubkey: hasAttestation ? deriveAttestationPDA(requester)[0] : PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    // Remaining accounts: REI transfer hook accounts for the move into escrow
+    ...transferHookAccounts(requester, GOVERNANCE_PDA, reitMint),
   ];
   
   return new TransactionInstruction({
@@ -521,6 +861,18 @@ export function formatUSD(cents) {
   }).format(cents / 100);
 }
 
+/**
+ * Format dollars to dollar string
+ * @param {number} dollars - Amount in dollars (e.g. NAV per token)
+ * @returns {string} Formatted dollar string
+ */
+export function formatDollars(dollars) {
+  return new Intl.NumberFormat('en-US', {
+    style: 'currency',
+    currency: 'USD',
+  }).format(dollars);
+}
+
 /**
  * Format large numbers with K, M, B suffixes
  * @param {number} num - Number to format
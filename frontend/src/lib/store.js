@@ -15,8 +15,8 @@ export const useStore = create((set, get) => ({
   // Fund State (from on-chain)
   // ========================================================================
   fundStats: {
-    currentNav: 5000,
-    previousNav: 5000,
+    currentNav: 50,
+    previousNav: 50,
     navTimestamp: null,
     totalSupply: 0,
     fundValue: 0,
@@ -73,7 +73,7 @@ export const useStore = create((set, get) => ({
       ...state.userHoldings,
       reiBalance,
       usdcBalance,
-      currentValue: reiBalance * (state.fundStats.currentNav / 100),
+      currentValue: reiBalance * state.fundStats.currentNav,
     } : null,
   })),
   
@@ -94,15 +94,15 @@ export const useStore = create((set, get) => ({
   getFundValue: () => {
     const { fundStats } = get();
     if (!fundStats) return 0;
-    // Fund value = total supply * NAV / 100 (NAV in cents)
-    return (fundStats.totalSupply * fundStats.currentNav) / 100;
+    // Fund value = total supply * NAV (NAV in dollars)
+    return fundStats.totalSupply * fundStats.currentNav;
   },
   
   getUserGainLoss: () => {
     const { userHoldings, fundStats } = get();
     if (!userHoldings || !fundStats) return { amount: 0, percent: 0 };
     
-    const currentValue = userHoldings.reiBalance * (fundStats.currentNav / 100);
+    const currentValue = userHoldings.reiBalance * fundStats.currentNav;
     const gainAmount = currentValue - userHoldings.totalInvested;
     const gainPercent = userHoldings.totalInvested > 0 
       ? (gainAmount / userHoldings.totalInvested) * 100 
@@ -123,8 +123,8 @@ export const useStore = create((set, get) => ({
       set((state) => ({ 
         fundStats: {
           ...state.fundStats,
-          currentNav: navData.navCents,
-          previousNav: navData.previousNavCents,
+          currentNav: navData.nav,
+          previousNav: navData.previousNav,
           navTimestamp: navData.timestamp,
           lastNavUpdate: Date.now(),
         },
@@ -190,7 +190,7 @@ export const useStore = create((set, get) => ({
 
 /**
  * Generate mock NAV history based on current NAV
- * @param {number} currentNav - Current NAV in cents
+ * @param {number} currentNav - Current NAV in dollars
  * @returns {Array}
  */
 function generateNavHistory(currentNav) {
@@ -200,11 +200,11 @@ function generateNavHistory(currentNav) {
   
   // Generate 30 days of history with slight variations
   for (let i = 30; i >= 0; i--) {
-    const variance = (Math.random() - 0.5) * 50; // ±$0.25
-    const nav = Math.max(4500, Math.min(5500, currentNav + variance - (i * 2)));
+    const variance = (Math.random() - 0.5) * 0.5; // ±$0.25
+    const nav = Math.max(45, Math.min(55, currentNav + variance - (i * 0.02)));
     
     history.push({
-      nav: Math.round(nav),
+      nav: Math.round(nav * 100) / 100,
       timestamp: now - (i * dayMs),
     });
   }
@@ -18,7 +18,7 @@
 
 /**
  * @typedef {Object} NavSnapshot
- * @property {number} nav - NAV in cents
+ * @property {number} nav - NAV in dollars
  * @property {number} timestamp - Unix timestamp in milliseconds
  * @property {string} [txSignature] - Transaction signature (optional)
  */
@@ -45,8 +45,8 @@
 
 /**
  * @typedef {Object} FundStats
- * @property {number} currentNav - Current NAV in cents
- * @property {number} previousNav - Previous NAV in cents
+ * @property {number} currentNav - Current NAV in dollars
+ * @property {number} previousNav - Previous NAV in dollars
  * @property {Date} navTimestamp - Last NAV update timestamp
  * @property {number} totalSupply - Total REI tokens in circulation (6 decimals)
  * @property {number} treasuryBalance - Treasury USDC balance (6 decimals)
@@ -57,8 +57,8 @@
 
 /**
  * @typedef {Object} ReiTokenState
- * @property {number} currentNav - Current NAV in cents
- * @property {number} previousNav - Previous NAV in cents
+ * @property {number} currentNav - Current NAV in dollars
+ * @property {number} previousNav - Previous NAV in dollars
  * @property {Date|null} navTimestamp - Last NAV update timestamp
  * @property {number} tokenSupply - Total REI token supply (6 decimals)
  * @property {number} userReiBalance - User's REI token balance (6 decimals)
@@ -85,7 +85,7 @@
  * @property {'subscribe' | 'redeem' | 'yield'} type
  * @property {number} amount - USDC amount in cents
  * @property {number} tokens - REI tokens involved
- * @property {number} nav - NAV at time of transaction (cents)
+ * @property {number} nav - NAV at time of transaction (dollars)
  * @property {number} timestamp - Unix timestamp in milliseconds
  * @property {string} signature - Transaction signature
  */
@@ -11,7 +11,11 @@
 import { useState, useEffect, useCallback, useRef } from 'react';
 import { useConnection, useWallet } from '@solana/wallet-adapter-react';
 import { PublicKey, Transaction } from '@solana/web3.js';
-import { getAssociatedTokenAddressSync, createAssociatedTokenAccountInstruction } from '@solana/spl-token';
+import {
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from '@solana/spl-token';
 
 import {
   PROGRAM_ID,
@@ -20,11 +24,15 @@ import {
   GOVERNANCE_PDA,
   fetchCurrentNav,
   fetchGovernance,
+  fetchPricingNav,
   fetchRedemptionQueue,
   fetchTokenBalance,
   fetchMintSupply,
+  fetchNextRedemptionRequestId,
   buildSubscribeInstruction,
   buildRequestRedeemInstruction,
+  minTokensOutFromQuote,
+  deriveAttestationPDA,
   deriveReitMintPDA,
   deriveTreasuryPDA,
   sendAndConfirmTransaction,
@@ -44,15 +52,17 @@ const REQUEST_DELAY = 500;
 
 /**
  * @typedef {Object} ReiTokenState
- * @property {number} currentNav - Current NAV in cents
- * @property {number} previousNav - Previous NAV in cents
+ * @property {number} currentNav - Current NAV in dollars
+ * @property {number} previousNav - Previous NAV in dollars
  * @property {Date} navTimestamp - Last NAV update timestamp
+ * @property {number|null} pricingNav - Raw 6-decimal NAV subscribe prices at (spot or TWAP)
+ * @property {string|null} pricingMode - Governance pricing mode ('spot' or 'twap')
  * @property {number} tokenSupply - Total REI token supply (6 decimals)
  * @property {number} userReiBalance - User's REI token balance
  * @property {number} userUsdcBalance - User's USDC balance
  * @property {number} treasuryBalance - Treasury USDC balance
- * @property {number} mintFeeBps - Mint fee in basis points
- * @property {number} redeemFeeBps - Redemption fee in basis points
+ * @property {number|null} mintFeeBps - Mint fee in basis points (null until governance loads)
+ * @property {number|null} redeemFeeBps - Redemption fee in basis points (null until governance loads)
  * @property {Array} redemptionQueue - Pending redemption requests
  */
 
@@ -80,12 +90,14 @@ export function useReiToken() {
     currentNav: 0,
     previousNav: 0,
     navTimestamp: null,
+    pricingNav: null,
+    pricingMode: null,
     tokenSupply: 0,
     userReiBalance: 0,
     userUsdcBalance: 0,
     treasuryBalance: 0,
-    mintFeeBps: 50,
-    redeemFeeBps: 100,
+    mintFeeBps: null,
+    redeemFeeBps: null,
     redemptionQueue: [],
   });
   
@@ -127,20 +139,29 @@ export function useReiToken() {
       const navData = await fetchCurrentNav();
       setData(prev => ({
         ...prev,
-        currentNav: navData?.navCents || 0,
-        previousNav: navData?.previousNavCents || 0,
+        currentNav: navData?.nav || 0,
+        previousNav: navData?.previousNav || 0,
         navTimestamp: navData?.timestamp || null,
       }));
       
       await delay(REQUEST_DELAY);
       
-      // 2. Fetch governance
+      // 2. Fetch governance and the NAV subscriptions are priced at
       const governance = await fetchGovernance();
       if (governance) {
         setData(prev => ({
           ...prev,
-          mintFeeBps: governance.mintFeeBps || 50,
-          redeemFeeBps: governance.redemptionFeeBps || 100,
+          mintFeeBps: governance.mintFeeBps,
+          redeemFeeBps: governance.redemptionFeeBps,
+        }));
+        
+        await delay(REQUEST_DELAY);
+        
+        const pricing = await fetchPricingNav(governance);
+        setData(prev => ({
+          ...prev,
+          pricingNav: pricing?.rawNav ?? null,
+          pricingMode: pricing?.mode ?? governance.pricingMode,
         }));
       }
       
@@ -166,7 +187,7 @@ export function useReiToken() {
       if (publicKey) {
         await delay(REQUEST_DELAY);
         
-        const reiBalance = await fetchTokenBalance(publicKey, reitMint, false, false);
+        const reiBalance = await fetchTokenBalance(publicKey, reitMint, false, false, TOKEN_2022_PROGRAM_ID);
         setData(prev => ({
           ...prev,
           userReiBalance: reiBalance || 0,
@@ -209,9 +230,10 @@ export function useReiToken() {
   /**
    * Subscribe to fund by depositing USDC
    * @param {number} usdcAmount - Amount in USDC (e.g., 100.5 = $100.50)
+   * @param {number} quotedTokens - REI quoted to the user; min_tokens_out is this less the slippage tolerance
    * @returns {Promise<string>} Transaction signature
    */
-  const subscribe = useCallback(async (usdcAmount) => {
+  const subscribe = useCallback(async (usdcAmount, quotedTokens) => {
     if (!connected || !publicKey || !signTransaction) {
       throw new Error('Wallet not connected');
     }
@@ -226,12 +248,19 @@ export function useReiToken() {
     try {
       // Convert to smallest units (6 decimals)
       const amountSmallest = Math.floor(usdcAmount * 1_000_000);
+      const minTokensOut = minTokensOutFromQuote(quotedTokens);
       
       // Get connection
       const conn = getConnection();
       
-      // Check if user has REI token account, create if not
-      const userReiAta = getAssociatedTokenAddressSync(reitMint, publicKey);
+      const governance = await fetchGovernance(true);
+      if (!governance) {
+        throw new Error('Fund governance not found');
+      }
+      const attestationInfo = await conn.getAccountInfo(deriveAttestationPDA(publicKey)[0]);
+      
+      // Check if user has REI token account (Token-2022), create if not
+      const userReiAta = getAssociatedTokenAddressSync(reitMint, publicKey, false, TOKEN_2022_PROGRAM_ID);
       const ataInfo = await conn.getAccountInfo(userReiAta);
       
       const transaction = new Transaction();
@@ -243,15 +272,15 @@ export function useReiToken() {
             publicKey,     // payer
             userReiAta,    // ata
             publicKey,     // owner
-            reitMint       // mint
+            reitMint,      // mint
+            TOKEN_2022_PROGRAM_ID
           )
         );
       }
       
       // Add subscribe instruction
-      const treasuryAta = getAssociatedTokenAddressSync(USDC_MINT, treasury, true);
       transaction.add(
-        buildSubscribeInstruction(publicKey, amountSmallest, reitMint, treasuryAta)
+        buildSubscribeInstruction(publicKey, amountSmallest, minTokensOut, governance, !!attestationInfo)
       );
       
       // Send transaction
@@ -275,7 +304,7 @@ export function useReiToken() {
     } finally {
       setIsSubmitting(false);
     }
-  }, [connected, publicKey, signTransaction, reitMint, treasury, refresh]);
+  }, [connected, publicKey, signTransaction, reitMint, refresh]);
 
   /**
    * Request redemption of REI tokens
@@ -304,9 +333,15 @@ export function useReiToken() {
     try {
       const conn = getConnection();
       
+      const requestId = await fetchNextRedemptionRequestId();
+      if (requestId === null) {
+        throw new Error('Redemption queue not found');
+      }
+      const attestationInfo = await conn.getAccountInfo(deriveAttestationPDA(publicKey)[0]);
+      
       const transaction = new Transaction();
       transaction.add(
-        buildRequestRedeemInstruction(publicKey, amountSmallest, reitMint)
+        buildRequestRedeemInstruction(publicKey, amountSmallest, requestId, reitMint, !!attestationInfo)
       );
       
       const signature = await sendAndConfirmTransaction(
@@ -35,10 +35,9 @@ export default function DashboardPage() {
 
   // Calculate values
   const currentNav = data?.currentNav || 0;
-  const navDisplay = toDisplayAmount(currentNav, 2);
   const reiBalance = toDisplayAmount(data?.userReiBalance || 0, 6);
   const tokenSupply = toDisplayAmount(data?.tokenSupply || 0, 6);
-  const currentValue = reiBalance * navDisplay;
+  const currentValue = reiBalance * currentNav;
 
   // Calculate share of fund
   const sharePercent = tokenSupply > 0 ? (reiBalance / tokenSupply * 100).toFixed(4) : '0.0000';
@@ -140,7 +139,7 @@ export default function DashboardPage() {
             },
             {
               label: 'Current NAV',
-              value: `$${navDisplay.toFixed(2)}`,
+              value: `$${currentNav.toFixed(2)}`,
               subValue: 'Per REI token',
               icon: BarChart3,
             },
@@ -66,19 +66,19 @@ export default function HomePage() {
   }, [refresh]);
 
   // Convert on-chain values for display
-  const currentNav = toDisplayAmount(data?.currentNav || 0, 2);
+  const currentNav = data?.currentNav || 0;
   const tokenSupply = toDisplayAmount(data?.tokenSupply || 0, 6);
   const treasuryBalance = toDisplayAmount(data?.treasuryBalance || 0, 6);
 
   // Mock history for chart until we have real history
   const navHistory = data?.currentNav ? [
-    { timestamp: Date.now() - 86400000 * 6, nav: (data.currentNav * 0.98) / 100 },
-    { timestamp: Date.now() - 86400000 * 5, nav: (data.currentNav * 0.985) / 100 },
-    { timestamp: Date.now() - 86400000 * 4, nav: (data.currentNav * 0.99) / 100 },
-    { timestamp: Date.now() - 86400000 * 3, nav: (data.currentNav * 0.995) / 100 },
-    { timestamp: Date.now() - 86400000 * 2, nav: (data.currentNav * 0.998) / 100 },
-    { timestamp: Date.now() - 86400000, nav: (data.currentNav * 0.999) / 100 },
-    { timestamp: Date.now(), nav: data.currentNav / 100 },
+    { timestamp: Date.now() - 86400000 * 6, nav: data.currentNav * 0.98 },
+    { timestamp: Date.now() - 86400000 * 5, nav: data.currentNav * 0.985 },
+    { timestamp: Date.now() - 86400000 * 4, nav: data.currentNav * 0.99 },
+    { timestamp: Date.now() - 86400000 * 3, nav: data.currentNav * 0.995 },
+    { timestamp: Date.now() - 86400000 * 2, nav: data.currentNav * 0.998 },
+    { timestamp: Date.now() - 86400000, nav: data.currentNav * 0.999 },
+    { timestamp: Date.now(), nav: data.currentNav },
   ] : [];
 
   // Fund stats for the grid
@@ -3,7 +3,7 @@ import VideoBackground from '../components/VideoBackground';
 import SubscribeForm from '../components/SubscribeForm';
 import RedeemForm from '../components/RedeemForm';
 import { useStore } from '../lib/store';
-import { formatUSD } from '../lib/solana';
+import { formatDollars } from '../lib/solana';
 import { TrendingUp, ArrowRightLeft } from 'lucide-react';
 
 export default function InvestPage() {
@@ -28,7 +28,7 @@ export default function InvestPage() {
                 <div className="glass-panel p-6 md:p-8 rounded-3xl mb-12 flex flex-col md:flex-row items-center justify-center gap-8 md:gap-16 animate-reveal delay-100">
                     <div className="text-center">
                         <p className="text-white/40 text-xs uppercase tracking-wider mb-2">Current NAV</p>
-                        <p className="text-4xl font-bold text-white">{formatUSD(fundStats?.currentNav || 5000)}</p>
+                        <p className="text-4xl font-bold text-white">{formatDollars(fundStats?.currentNav || 50)}</p>
                     </div>
                     <div className="hidden md:block w-px h-12 bg-white/10" />
                     <div className="text-center">
@@ -6,7 +6,7 @@ import ProjectDetailModal from '../components/ProjectDetailModal';
 import { useStore } from '../lib/store';
 import { fetchProjects, fetchNav, checkOracleHealth } from '../lib/oracleApi';
 import { MOCK_PROJECTS } from '../lib/mockData';
-import { formatUSD, formatCompact, formatDate } from '../lib/solana';
+import { formatDollars, formatCompact, formatDate } from '../lib/solana';
 import { PieChart, Pie, Cell, ResponsiveContainer, BarChart, Bar, XAxis, YAxis, Tooltip } from 'recharts';
 import {
     Building2,
@@ -365,7 +365,7 @@ export default function PortfolioPage() {
                                             <DollarSign className="w-5 h-5 text-white/40" />
                                             <p className="text-white/40 text-sm uppercase tracking-wider">Current NAV</p>
                                         </div>
-                                        <p className="text-5xl font-bold text-white">{formatUSD(currentNav)}</p>
+                                        <p className="text-5xl font-bold text-white">{formatDollars(currentNav)}</p>
                                     </div>
 
                                     <div className="flex flex-wrap gap-12">
@@ -445,7 +445,7 @@ export default function PortfolioPage() {
                                                                 {formatDate(snapshot.timestamp)}
                                                             </td>
                                                             <td className="py-4 text-white font-medium">
-                                                                {formatUSD(snapshot.nav)}
+                                                                {formatDollars(snapshot.nav)}
                                                             </td>
                                                             <td className="py-4 text-right">
                                                                 <span className={`inline-flex items-center gap-1 px-2 py-1 rounded-full text-[10px] font-bold ${isPositive
@@ -9,7 +9,7 @@ import json
 from pathlib import Path
 from flask import Flask, jsonify
 from flask_cors import CORS
-from nav_engine import NavEngine
+from nav_engine import NavEngine, NAV_DECIMALS, NAV_SCALE
 
 app = Flask(__name__)
 CORS(app)  # Enable CORS for frontend access
@@ -105,13 +105,14 @@ def get_nav():
         return jsonify({"error": "NAV engine not initialized"}), 500
     
     try:
-        nav_cents, timestamp = nav_engine.compute_nav()
+        nav, timestamp = nav_engine.compute_nav()
         breakdown = nav_engine.get_nav_breakdown()
         monthly_yield = nav_engine.get_total_monthly_yield()
         
         return jsonify({
-            "nav_cents": nav_cents,
-            "nav_usd": round(nav_cents / 100, 2),
+            "nav": nav,
+            "nav_decimals": NAV_DECIMALS,
+            "nav_usd": round(nav / NAV_SCALE, NAV_DECIMALS),
             "timestamp": timestamp,
             "monthly_yield_usd": monthly_yield,
             "breakdown": breakdown
@@ -13,6 +13,16 @@ from dataclasses import dataclass
 from typing import Tuple, List, Dict, Any
 
 
+# On-chain NAV is fixed point with 6 decimals (matches USDC and REI)
+NAV_DECIMALS = 6
+NAV_SCALE = 10 ** NAV_DECIMALS
+
+
+def format_nav(nav: int) -> str:
+    """Format a 6-decimal fixed-point NAV as dollars (e.g., 47601234 -> $47.601234)."""
+    return f"${nav / NAV_SCALE:.{NAV_DECIMALS}f}"
+
+
 @dataclass
 class Project:
     """Represents a renewable energy project in the fund."""
@@ -91,15 +101,19 @@ class NavEngine:
         """Reload project data from file (for hot-reloading)."""
         self._load_data()
     
-    def compute_nav(self) -> Tuple[int, int]:
+    def compute_nav(self, reinvested_usdc: float = 0) -> Tuple[int, int]:
         """
         Compute the current NAV per token.
         
-        Formula: NAV = (Σ project_dcf + cash - debt - capex) / token_supply
+        Formula: NAV = (Σ project_dcf + cash + reinvested - debt - capex) / token_supply
+        
+        Args:
+            reinvested_usdc: Yield reinvested on-chain (YieldState.total_reinvested, in USD).
+                It stays in the treasury, so it counts as fund cash on top of projects.json.
         
         Returns:
-            Tuple of (nav_in_cents: int, timestamp: int)
-            - nav_in_cents: NAV per token in cents (e.g., 4760 = $47.60)
+            Tuple of (nav: int, timestamp: int)
+            - nav: NAV per token, 6-decimal fixed point (e.g., 47_601_234 = $47.601234)
             - timestamp: Unix timestamp in seconds
         """
         # Step 1-2: Sum all DCF valuations from operational projects only
@@ -110,13 +124,13 @@ class NavEngine:
         )
         
         # Step 3: Get fund metadata values
-        cash = self.fund_metadata.total_cash_on_hand_usdc
+        cash = self.fund_metadata.total_cash_on_hand_usdc + reinvested_usdc
         debt = self.fund_metadata.total_debt
         capex = self.fund_metadata.pending_capex
         supply = self.fund_metadata.token_supply
         
         # Step 4: Calculate net asset value
-        # NAV = (project_valuations + cash - debt - capex)
+        # NAV = (project_valuations + cash (incl. reinvested yield) - debt - capex)
         net_asset_value = sum_valuations + cash - debt - capex
         
         # Step 5: Calculate NAV per token (in dollars)
@@ -124,20 +138,18 @@ class NavEngine:
         if supply <= 0:
             raise ValueError("Token supply must be positive")
         
-        nav_per_token = net_asset_value / supply
-        
-        # Step 6: Convert to cents using round() for proper rounding
-        # Use round() instead of int() to avoid truncation errors
-        nav_in_cents = int(round(nav_per_token * 100))
+        # Step 6: Convert to the on-chain 6-decimal fixed point using round()
+        # for proper rounding (scale before dividing to keep full precision)
+        nav = int(round(net_asset_value * NAV_SCALE / supply))
         
         # Ensure NAV is positive (sanity check)
-        if nav_in_cents <= 0:
-            raise ValueError(f"Calculated NAV is non-positive: {nav_in_cents}")
+        if nav <= 0:
+            raise ValueError(f"Calculated NAV is non-positive: {nav}")
         
         # Step 7: Return NAV and current Unix timestamp
         timestamp = int(time.time())
         
-        return (nav_in_cents, timestamp)
+        return (nav, timestamp)
     
     def simulate_monthly_yield(self) -> Dict[str, Any]:
         """
@@ -289,7 +301,7 @@ class NavEngine:
         
         net_asset_value = sum_valuations + cash - debt - capex
         nav_per_token = net_asset_value / supply if supply > 0 else 0
-        nav_in_cents = int(round(nav_per_token * 100))
+        nav = int(round(net_asset_value * NAV_SCALE / supply)) if supply > 0 else 0
         
         return {
             'sum_project_valuations': sum_valuations,
@@ -298,8 +310,8 @@ class NavEngine:
             'pending_capex': capex,
             'net_asset_value': net_asset_value,
             'token_supply': supply,
-            'nav_per_token_usd': round(nav_per_token, 2),
-            'nav_in_cents': nav_in_cents,
+            'nav_per_token_usd': round(nav_per_token, NAV_DECIMALS),
+            'nav': nav,
             'operational_projects': len([p for p in self.projects if p.status == 'operational']),
             'total_projects': len(self.projects)
         }
@@ -358,9 +370,9 @@ if __name__ == '__main__':
                               f"(weather: {proj['weather_variance']:.2%})")
             print()
         
-        nav_cents, timestamp = engine.compute_nav()
+        nav, timestamp = engine.compute_nav()
         
-        print(f"NAV: ${nav_cents / 100:.2f} ({nav_cents} cents)")
+        print(f"NAV: {format_nav(nav)} ({nav} at {NAV_DECIMALS} decimals)")
         print(f"Timestamp: {timestamp}")
         print(f"Total Monthly Yield: ${engine.get_total_monthly_yield():,}")
         
@@ -35,7 +35,7 @@ except ImportError:
     print("ERROR: httpx library not installed. Run: pip install httpx")
     sys.exit(1)
 
-from nav_engine import NavEngine
+from nav_engine import NavEngine, format_nav
 
 # Configure logging
 logging.basicConfig(
@@ -62,6 +62,9 @@ class OracleService:
     # Generated by: sha256("global:submit_nav")[0:8]
     SUBMIT_NAV_DISCRIMINATOR = bytes([132, 186, 250, 155, 121, 127, 246, 0])
     
+    # USDC base units per dollar
+    USDC_SCALE = 1_000_000
+    
     def __init__(
         self,
         program_id: str,
@@ -102,7 +105,11 @@ class OracleService:
         self.nav_oracle_pda = self._derive_or_use_pda(
             nav_oracle_pda, b"nav_oracle"
         )
+        self.oracle_config_pda = self._derive_or_use_pda(None, b"oracle_config")
+        self.nav_history_pda = self._derive_or_use_pda(None, b"nav_history")
+        self.yield_state_pda = self._derive_or_use_pda(None, b"yield_state")
         logger.info(f"Governance PDA: {self.governance_pda}")
+        logger.info(f"Oracle Config PDA: {self.oracle_config_pda}")
         logger.info(f"NAV Oracle PDA: {self.nav_oracle_pda}")
         
         # Initialize NAV engine
@@ -206,26 +213,30 @@ class OracleService:
         
         return result.get("result", {}).get("value")
     
-    def _build_submit_nav_instruction(self, nav_in_cents: int) -> Instruction:
+    def _build_submit_nav_instruction(self, nav: int) -> Instruction:
         """
         Build the submit_nav instruction.
         
         Accounts required:
         1. oracle_signer (signer) - Oracle keypair
-        2. governance (readonly) - Governance PDA
-        3. nav_oracle (writable) - NAV Oracle PDA
+        2. governance (writable) - Governance PDA (completes oracle key rotations)
+        3. oracle_config (writable) - Oracle Config PDA (pending quorum round)
+        4. nav_oracle (writable) - NAV Oracle PDA
+        5. nav_history (writable) - NAV History PDA (ring of past updates)
         
-        Data: 8-byte discriminator + 8-byte u64 nav
+        Data: 8-byte discriminator + 8-byte u64 nav (6 decimals)
         """
         # Build instruction data
-        # Discriminator (8 bytes) + nav_in_cents (u64, 8 bytes little-endian)
-        data = self.SUBMIT_NAV_DISCRIMINATOR + struct.pack('<Q', nav_in_cents)
+        # Discriminator (8 bytes) + nav (u64, 6 decimals, 8 bytes little-endian)
+        data = self.SUBMIT_NAV_DISCRIMINATOR + struct.pack('<Q', nav)
         
         # Build account metas
         accounts = [
             AccountMeta(pubkey=self.oracle_keypair.pubkey(), is_signer=True, is_writable=False),
-            AccountMeta(pubkey=self.governance_pda, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=self.governance_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=self.oracle_config_pda, is_signer=False, is_writable=True),
             AccountMeta(pubkey=self.nav_oracle_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=self.nav_history_pda, is_signer=False, is_writable=True),
         ]
         
         return Instruction(
@@ -234,20 +245,20 @@ class OracleService:
             data=data
         )
     
-    async def submit_nav(self, nav_in_cents: int) -> str:
+    async def submit_nav(self, nav: int) -> str:
         """
         Submit a NAV update to the Solana program.
         
         Args:
-            nav_in_cents: NAV value in cents (e.g., 4760 = $47.60)
+            nav: NAV per token, 6-decimal fixed point (e.g., 47_601_234 = $47.601234)
             
         Returns:
             Transaction signature string
         """
-        logger.info(f"Building submit_nav transaction for NAV: {nav_in_cents} cents (${nav_in_cents/100:.2f})")
+        logger.info(f"Building submit_nav transaction for NAV: {nav} ({format_nav(nav)})")
         
         # Build instruction
-        instruction = self._build_submit_nav_instruction(nav_in_cents)
+        instruction = self._build_submit_nav_instruction(nav)
         
         # Get latest blockhash
         blockhash = await self._get_latest_blockhash()
@@ -274,7 +285,7 @@ class OracleService:
         Read the current NAV value from the on-chain oracle account.
         
         Returns:
-            NAV in cents if readable, None otherwise
+            NAV (6-decimal fixed point) if readable, None otherwise
         """
         account_info = await self._get_account_info(self.nav_oracle_pda)
         
@@ -292,7 +303,9 @@ class OracleService:
         # 8 bytes previous_nav (u64)
         # 8 bytes timestamp (i64)
         # 1 byte bump
-        if len(data) < 33:
+        # 16 bytes cumulative_nav_seconds (u128)
+        # 8 bytes cumulative_timestamp (i64)
+        if len(data) < 57:
             logger.warning(f"NAV Oracle data too short: {len(data)} bytes")
             return None
         
@@ -300,9 +313,46 @@ class OracleService:
         previous_nav = struct.unpack('<Q', data[16:24])[0]
         timestamp = struct.unpack('<q', data[24:32])[0]
         
-        logger.debug(f"On-chain NAV: {latest_nav} cents, previous: {previous_nav}, timestamp: {timestamp}")
+        logger.debug(
+            f"On-chain NAV: {format_nav(latest_nav)}, previous: {format_nav(previous_nav)}, "
+            f"timestamp: {timestamp}"
+        )
         return latest_nav
     
+    async def fetch_reinvested_usdc(self) -> float:
+        """
+        Read the yield reinvested into NAV from the on-chain YieldState.
+        
+        distribute_yield in reinvest mode keeps the USDC in the treasury and raises
+        the on-chain NAV; adding it to fund cash keeps that uplift in later submissions.
+        
+        Returns:
+            Total reinvested yield in USD (0 if the account is missing)
+        """
+        account_info = await self._get_account_info(self.yield_state_pda)
+        
+        if not account_info or not account_info.get('data'):
+            logger.warning("YieldState account not found, assuming no reinvested yield")
+            return 0
+        
+        import base64
+        data = base64.b64decode(account_info['data'][0])
+        
+        # YieldState structure:
+        # 8 bytes discriminator
+        # 16 bytes reward_per_token (u128)
+        # 8 bytes total_distributed (u64)
+        # 8 bytes total_claimed (u64)
+        # 8 bytes total_reinvested (u64)
+        # 8 bytes earning_supply (u64)
+        # 1 byte bump
+        if len(data) < 57:
+            logger.warning(f"YieldState data too short: {len(data)} bytes")
+            return 0
+        
+        total_reinvested = struct.unpack('<Q', data[40:48])[0]
+        return total_reinvested / self.USDC_SCALE
+    
     async def run_oracle_loop(
         self,
         interval_seconds: int = 3600,
@@ -325,28 +375,29 @@ class OracleService:
                     result = self.nav_engine.simulate_monthly_yield()
                     logger.info(f"Simulated monthly yield for {result['projects_simulated']} projects")
                 
-                # Compute NAV
-                nav_cents, timestamp = self.nav_engine.compute_nav()
-                logger.info(f"Computed NAV: {nav_cents} cents (${nav_cents/100:.2f})")
+                # Compute NAV (including yield reinvested on-chain)
+                reinvested_usdc = await self.fetch_reinvested_usdc()
+                nav, timestamp = self.nav_engine.compute_nav(reinvested_usdc)
+                logger.info(f"Computed NAV: {nav} ({format_nav(nav)})")
                 
                 # Submit NAV
-                signature = await self.submit_nav(nav_cents)
+                signature = await self.submit_nav(nav)
                 
                 # Update stats
                 self.submission_count += 1
                 self.last_submission_time = time.time()
-                self.last_nav = nav_cents
+                self.last_nav = nav
                 
                 # Verify on-chain (optional)
                 await asyncio.sleep(5)  # Wait for confirmation
                 on_chain_nav = await self.verify_nav_on_chain()
                 if on_chain_nav:
-                    logger.info(f"Verified on-chain NAV: {on_chain_nav} cents")
+                    logger.info(f"Verified on-chain NAV: {format_nav(on_chain_nav)}")
                 
                 # Log stats
                 logger.info(
                     f"Stats: submissions={self.submission_count}, "
-                    f"last_nav={self.last_nav} cents"
+                    f"last_nav={format_nav(self.last_nav)}"
                 )
                 
                 # Wait for next interval
@@ -367,11 +418,12 @@ class OracleService:
         Compute and submit NAV once (for testing).
         
         Returns:
-            Tuple of (nav_in_cents, signature)
+            Tuple of (nav, signature), NAV as 6-decimal fixed point
         """
-        nav_cents, timestamp = self.nav_engine.compute_nav()
-        signature = await self.submit_nav(nav_cents)
-        return nav_cents, signature
+        reinvested_usdc = await self.fetch_reinvested_usdc()
+        nav, timestamp = self.nav_engine.compute_nav(reinvested_usdc)
+        signature = await self.submit_nav(nav)
+        return nav, signature
     
     async def close(self):
         """Close the HTTP client."""
@@ -444,13 +496,13 @@ async def main():
         if args.verify_only:
             nav = await service.verify_nav_on_chain()
             if nav:
-                print(f"On-chain NAV: {nav} cents (${nav/100:.2f})")
+                print(f"On-chain NAV: {format_nav(nav)} ({nav})")
             else:
                 print("Could not read NAV from chain")
                 
         elif args.once:
             nav, sig = await service.submit_once()
-            print(f"Submitted NAV: {nav} cents (${nav/100:.2f})")
+            print(f"Submitted NAV: {format_nav(nav)} ({nav})")
             print(f"Signature: {sig}")
             print(f"Explorer: https://explorer.solana.com/tx/{sig}?cluster=devnet")
             